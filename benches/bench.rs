@@ -115,6 +115,26 @@ fn next_peek(c: &mut Criterion) {
     });
 }
 
+fn next_single_peek(c: &mut Criterion) {
+    let mut group = c.benchmark_group("next() + single peek()");
+    let mut multipeek = (0..1000).multipeek();
+    let mut peeking_iter = PeekingIter::new(0..1000);
+
+    group.bench_function(BenchmarkId::new("itertools::MultiPeek", "(0..1000)"), |b| {
+        b.iter(|| {
+            multipeek.next();
+            multipeek.peek();
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("PeekingIter", "(0..1000)"), |b| {
+        b.iter(|| {
+            peeking_iter.next();
+            peeking_iter.peek();
+        });
+    });
+}
+
 fn next_peek_random(c: &mut Criterion) {
     let mut group = c.benchmark_group("next() + peek()");
     let it = iter::from_fn(|| Some(random::<u32>())).cycle();
@@ -140,6 +160,22 @@ fn next_peek_random(c: &mut Criterion) {
     });
 }
 
+fn sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum()");
+
+    group.bench_function(BenchmarkId::new("std::ops::Range", "(0..1000)"), |b| {
+        b.iter(|| (0..1000).sum::<u32>())
+    });
+
+    group.bench_function(BenchmarkId::new("itertools::MultiPeek", "(0..1000)"), |b| {
+        b.iter(|| (0..1000).multipeek().sum::<u32>())
+    });
+
+    group.bench_function(BenchmarkId::new("PeekingIter", "(0..1000)"), |b| {
+        b.iter(|| PeekingIter::new(0..1000).sum::<u32>())
+    });
+}
+
 fn next_while(c: &mut Criterion) {
     let it = iter::from_fn(|| Some(random::<u32>())).cycle();
     let mut peeking_iter = PeekingIter::new(it);
@@ -149,7 +185,7 @@ fn next_while(c: &mut Criterion) {
     });
 }
 
-criterion_group! { compare_preset, peek, next, next_peek }
+criterion_group! { compare_preset, peek, next, next_peek, next_single_peek, sum }
 criterion_group! { compare_random, peek_random, next_random, next_peek_random }
 criterion_group! { targeted, next_while }
 criterion_main! { compare_preset, compare_random }
@@ -0,0 +1,235 @@
+//! Derive macro for `peeking_iter`'s `FromParser`.
+//!
+//! Use it through the `derive` feature of `peeking-iter`, rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr};
+
+/// Options set with `#[parse(...)]`.
+#[derive(Default)]
+struct Options {
+    prefix: Option<LitStr>,
+    sep: Option<LitStr>,
+}
+
+impl Options {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("parse")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("prefix") {
+                    options.prefix = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("sep") {
+                    options.sep = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `prefix` or `sep`"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Derives `FromParser` from literal prefixes and field types.
+///
+/// Fields are parsed in order with their own `FromParser` implementations.
+/// The `parse` attribute takes:
+///
+/// - `prefix = "..."`: a literal expected before the struct, variant or
+///   field.
+/// - `sep = "..."`: a literal expected between the fields of the struct or
+///   variant. On an enum, it's the default for all variants.
+///
+/// Enum variants are tried in order, and the error of the one that got the
+/// furthest is returned if none matches. Unit variants need a prefix.
+///
+/// ```rust
+/// use peeking_iter::parser::{FromParser, Parser};
+///
+/// #[derive(Debug, PartialEq, FromParser)]
+/// #[parse(prefix = "v", sep = ".")]
+/// struct Version(u32, u32, u32);
+///
+/// #[derive(Debug, PartialEq, FromParser)]
+/// enum Req {
+///     #[parse(prefix = "=")]
+///     Exact(Version),
+///     #[parse(prefix = ">=")]
+///     AtLeast(Version),
+///     #[parse(prefix = "*")]
+///     Any,
+/// }
+///
+/// let mut it = Parser::new(">=v1.2.3".chars());
+///
+/// assert_eq!(it.parse(), Ok(Req::AtLeast(Version(1, 2, 3))));
+///
+/// let mut it = Parser::new("=v1.x".chars());
+/// let err = it.parse::<Req>().unwrap_err();
+///
+/// assert_eq!((err.position.col, err.found), (4, Some('x')));
+/// ```
+#[proc_macro_derive(FromParser, attributes(parse))]
+pub fn derive_from_parser(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let options = Options::from_attrs(&input.attrs)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => parse_fields(quote!(Self), &data.fields, &options)?,
+        Data::Enum(data) => {
+            if data.variants.is_empty() {
+                return Err(Error::new_spanned(
+                    name,
+                    "`FromParser` can't be derived for an enum without variants",
+                ));
+            }
+
+            let prefix = expect_str(options.prefix.as_ref());
+            let attempts = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let mut variant_options = Options::from_attrs(&variant.attrs)?;
+
+                    if variant_options.prefix.is_none() && variant.fields.is_empty() {
+                        return Err(Error::new_spanned(
+                            variant,
+                            "unit variants need `#[parse(prefix = \"...\")]`",
+                        ));
+                    }
+
+                    if variant_options.sep.is_none() {
+                        variant_options.sep.clone_from(&options.sep);
+                    }
+
+                    let body = parse_fields(quote!(Self::#ident), &variant.fields, &variant_options)?;
+
+                    Ok(quote! {
+                        let attempt = parser.attempt(
+                            |parser| -> ::core::result::Result<Self, ::peeking_iter::parser::ParseError> {
+                                #body
+                            },
+                        );
+
+                        match attempt {
+                            ::core::result::Result::Ok(value) => return ::core::result::Result::Ok(value),
+                            ::core::result::Result::Err(err) => {
+                                let further = error
+                                    .as_ref()
+                                    .map_or(true, |e: &::peeking_iter::parser::ParseError| {
+                                        err.position.offset > e.position.offset
+                                    });
+
+                                if further {
+                                    error = ::core::option::Option::Some(err);
+                                }
+                            }
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! {
+                #prefix
+
+                let mut error = ::core::option::Option::None;
+
+                #(#attempts)*
+
+                ::core::result::Result::Err(error.expect("an enum with variants"))
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "`FromParser` can't be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::peeking_iter::parser::FromParser for #name #ty_generics #where_clause {
+            fn parse<__I>(
+                parser: &mut ::peeking_iter::Parser<__I>,
+            ) -> ::core::result::Result<Self, ::peeking_iter::parser::ParseError>
+            where
+                __I: ::core::iter::Iterator<Item = char> + ::core::clone::Clone,
+            {
+                #body
+            }
+        }
+    })
+}
+
+/// Generates code parsing `fields` into `constructor`.
+fn parse_fields(
+    constructor: TokenStream2,
+    fields: &Fields,
+    options: &Options,
+) -> syn::Result<TokenStream2> {
+    let prefix = expect_str(options.prefix.as_ref());
+    let mut statements = Vec::new();
+    let mut values = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let field_options = Options::from_attrs(&field.attrs)?;
+
+        if let Some(sep) = field_options.sep {
+            return Err(Error::new_spanned(sep, "`sep` can't be set on a field"));
+        }
+
+        let sep = expect_str(options.sep.as_ref().filter(|_| i > 0));
+        let field_prefix = expect_str(field_options.prefix.as_ref());
+        let value = format_ident!("field_{}", i);
+        let ty = &field.ty;
+
+        statements.push(quote! {
+            #sep
+            #field_prefix
+            let #value = <#ty as ::peeking_iter::parser::FromParser>::parse(parser)?;
+        });
+        values.push(value);
+    }
+
+    let value = match fields {
+        Fields::Named(_) => {
+            let names = fields.iter().map(|field| &field.ident);
+
+            quote!(#constructor { #(#names: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#constructor(#(#values),*)),
+        Fields::Unit => constructor,
+    };
+
+    Ok(quote! {
+        #prefix
+        #(#statements)*
+
+        ::core::result::Result::Ok(#value)
+    })
+}
+
+/// Generates a call to `expect_str()`, if there's a literal.
+fn expect_str(literal: Option<&LitStr>) -> TokenStream2 {
+    match literal {
+        Some(literal) => quote!(parser.expect_str(#literal)?;),
+        None => TokenStream2::new(),
+    }
+}
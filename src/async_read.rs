@@ -0,0 +1,240 @@
+use crate::{
+    parser::{self, Expected, LineEndings, ParseError},
+    read::{Utf8Decoder, CHUNK},
+    Position,
+};
+use alloc::{collections::VecDeque, string::String};
+use core::{future::poll_fn, pin::Pin};
+use futures_io::AsyncRead;
+use std::io;
+
+/// A [`Parser`](crate::Parser) over a [`futures_io::AsyncRead`], decoded as
+/// UTF-8 (invalid sequences become `U+FFFD`).
+///
+/// Since readers can't be cloned, peeked characters are kept in an internal
+/// buffer. Lines and columns are tracked the same way as in `Parser`.
+///
+/// ```rust
+/// # use peeking_iter::parser::AsyncParser;
+/// # futures::executor::block_on(async {
+/// let mut it = AsyncParser::new("ab\ncd".as_bytes());
+///
+/// assert_eq!(it.next().await, Some('a'));
+/// assert_eq!(it.peek().await, Some('b'));
+/// assert_eq!(it.peek().await, Some('\n'));
+/// assert_eq!(it.next().await, Some('b'));
+///
+/// it.expect('\n').await.unwrap();
+/// assert_eq!((it.line(), it.col()), (2, 0));
+///
+/// assert_eq!(it.next_while(|c| c.is_alphabetic()).await, "cd");
+/// assert_eq!(it.next().await, None);
+/// # });
+/// ```
+pub struct AsyncParser<R> {
+    reader: R,
+    decoder: Utf8Decoder,
+    buf: VecDeque<char>,
+    peeked: usize,
+    pos: Position,
+    after_cr: bool,
+    line_endings: LineEndings,
+    eof: bool,
+    error: Option<io::Error>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncParser<R> {
+    /// Wraps the given reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            decoder: Utf8Decoder::default(),
+            buf: VecDeque::new(),
+            peeked: 0,
+            pos: Position::default(),
+            after_cr: false,
+            line_endings: LineEndings::default(),
+            eof: false,
+            error: None,
+        }
+    }
+
+    /// Sets the line endings used for line and column counting.
+    ///
+    /// Defaults to [`LineEndings::Any`].
+    pub fn line_endings(mut self, line_endings: LineEndings) -> Self {
+        self.line_endings = line_endings;
+        self
+    }
+
+    /// Reads from the inner reader until the buffer holds `n` characters.
+    ///
+    /// Returns `false` if the input ended before that.
+    async fn fill(&mut self, n: usize) -> bool {
+        let mut chunk = [0; CHUNK];
+
+        while self.buf.len() < n && !self.eof {
+            let read = poll_fn(|cx| Pin::new(&mut self.reader).poll_read(cx, &mut chunk)).await;
+
+            let n = match read {
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.error = Some(e);
+                    0
+                }
+            };
+
+            self.eof = n == 0;
+            self.decoder.decode(&chunk[..n], self.eof, &mut self.buf);
+        }
+
+        self.buf.len() >= n
+    }
+
+    /// Returns the next character.
+    ///
+    /// Resets the peeking cursor.
+    pub async fn next(&mut self) -> Option<char> {
+        self.peeked = 0;
+
+        if !self.fill(1).await {
+            return None;
+        }
+
+        let c = self.buf.pop_front()?;
+
+        // A `\r` needs the next character to tell whether it ends a line
+        if c == '\r' {
+            self.fill(1).await;
+        }
+
+        parser::step(
+            &mut self.pos,
+            &mut self.after_cr,
+            self.line_endings,
+            c,
+            || self.buf.front().copied(),
+        );
+
+        Some(c)
+    }
+
+    /// Peeks the next character.
+    ///
+    /// Subsequent calls return subsequent characters.
+    pub async fn peek(&mut self) -> Option<char> {
+        if !self.fill(self.peeked + 1).await {
+            return None;
+        }
+
+        self.peeked += 1;
+
+        self.buf.get(self.peeked - 1).copied()
+    }
+
+    /// Advances the parser to be aligned with the peeking cursor.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::AsyncParser;
+    /// # futures::executor::block_on(async {
+    /// let mut it = AsyncParser::new("abc".as_bytes());
+    ///
+    /// it.peek().await;
+    /// it.peek().await;
+    /// it.advance_to_peeked().await;
+    ///
+    /// assert_eq!(it.col(), 2);
+    /// assert_eq!(it.next().await, Some('c'));
+    /// # });
+    /// ```
+    pub async fn advance_to_peeked(&mut self) {
+        let n = self.peeked;
+
+        for _ in 0..n {
+            self.next().await;
+        }
+    }
+
+    /// Rewind the peeking cursor to align with the parser.
+    pub fn rewind_peeking(&mut self) {
+        self.peeked = 0;
+    }
+
+    /// Consumes the next character if it's `c`.
+    ///
+    /// Otherwise returns an error at the current position and leaves the
+    /// parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{AsyncParser, Expected};
+    /// # futures::executor::block_on(async {
+    /// let mut it = AsyncParser::new("ab".as_bytes());
+    ///
+    /// let err = it.expect('b').await.unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Char('b'));
+    /// assert_eq!(err.found, Some('a'));
+    /// assert_eq!(it.expect('a').await, Ok('a'));
+    /// # });
+    /// ```
+    pub async fn expect(&mut self, c: char) -> Result<char, ParseError> {
+        self.rewind_peeking();
+
+        match self.peek().await {
+            Some(x) if x == c => {
+                self.next().await;
+                Ok(c)
+            }
+            found => {
+                self.rewind_peeking();
+
+                Err(ParseError::new(self.pos, found, Expected::Char(c)))
+            }
+        }
+    }
+
+    /// Returns a `String` containing all continuous characters that the
+    /// predicate returns `true` for.
+    pub async fn next_while<F: Fn(char) -> bool>(&mut self, pred: F) -> String {
+        let mut result = String::new();
+
+        self.rewind_peeking();
+
+        while self.fill(1).await && pred(self.buf[0]) {
+            result.extend(self.next().await);
+        }
+
+        result
+    }
+
+    /// Returns the current line (starting at 1).
+    pub fn line(&self) -> usize {
+        self.pos.line
+    }
+
+    /// Returns the current column (starting at 0).
+    pub fn col(&self) -> usize {
+        self.pos.col
+    }
+
+    /// Returns the current position.
+    pub fn position(&self) -> Position {
+        self.pos
+    }
+
+    /// Takes the I/O error that ended the input, if any.
+    ///
+    /// A reader error looks like the end of input to the parser, so this
+    /// should be checked when parsing fails.
+    pub fn take_io_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    /// Consumes `self` and returns the inner reader.
+    ///
+    /// **NOTE:** Any buffered (peeked) input is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
@@ -0,0 +1,33 @@
+use crate::PeekingIter;
+
+/// Iterator yielding whatever a closure produces from the wrapped
+/// [`PeekingIter`].
+///
+/// Created by [`PeekingIter::batches()`].
+pub struct Batches<I: Iterator, F> {
+    iter: PeekingIter<I>,
+    f: F,
+}
+
+impl<I: Iterator + Clone, F> Batches<I, F> {
+    pub(crate) fn new(iter: PeekingIter<I>, f: F) -> Self {
+        Self { iter, f }
+    }
+
+    /// Consumes `self` and returns the wrapped [`PeekingIter`].
+    pub fn into_inner(value: Self) -> PeekingIter<I> {
+        value.iter
+    }
+}
+
+impl<B, I, F> Iterator for Batches<I, F>
+where
+    I: Iterator + Clone,
+    F: FnMut(&mut PeekingIter<I>) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        (self.f)(&mut self.iter)
+    }
+}
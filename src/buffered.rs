@@ -0,0 +1,183 @@
+use alloc::{boxed::Box, collections::VecDeque};
+
+/// Iterator adapter that enables infinitely-deep peeking by buffering the
+/// peeked items.
+///
+/// Unlike [`PeekingIter`](crate::PeekingIter), every item is produced by the
+/// inner iterator only once, so repeated lookahead (e.g. calling
+/// [`peek_nth()`](Self::peek_nth()) at varying depths after
+/// [`rewind_peeking()`](Self::rewind_peeking())) doesn't re-walk the same
+/// prefix. The inner iterator isn't required to implement [`Clone`].
+///
+/// Peeked items are returned by reference.
+///
+/// ```rust
+/// # use peeking_iter::BufferedPeekingIter;
+/// let mut it = BufferedPeekingIter::new(0..=2);
+///
+/// assert_eq!(it.next(), Some(0));
+/// assert_eq!(it.peek(), Some(&1));
+/// assert_eq!(it.peek(), Some(&2));
+/// assert_eq!(it.next(), Some(1));
+/// assert_eq!(it.peek(), Some(&2));
+/// assert_eq!(it.peek(), None);
+/// ```
+pub struct BufferedPeekingIter<I: Iterator> {
+    iter: I,
+    buf: VecDeque<I::Item>,
+    peek_offset: usize,
+}
+
+/// A [`BufferedPeekingIter`] over a type-erased iterator.
+///
+/// Can be stored in non-generic structs, see
+/// [`boxed()`](BufferedPeekingIter::boxed()).
+pub type DynPeekingIter<'a, T> = BufferedPeekingIter<Box<dyn Iterator<Item = T> + 'a>>;
+
+impl<'a, T> DynPeekingIter<'a, T> {
+    /// Boxes and wraps the given iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::DynPeekingIter;
+    /// struct Lexer {
+    ///     input: DynPeekingIter<'static, char>,
+    /// }
+    ///
+    /// let mut lexer = Lexer {
+    ///     input: DynPeekingIter::boxed("ab".chars().rev()),
+    /// };
+    ///
+    /// assert_eq!(lexer.input.peek(), Some(&'b'));
+    /// assert_eq!(lexer.input.next(), Some('b'));
+    /// ```
+    pub fn boxed<J: Iterator<Item = T> + 'a>(iter: J) -> Self {
+        Self::new(Box::new(iter))
+    }
+}
+
+impl<I: Iterator> BufferedPeekingIter<I> {
+    /// Wraps the given iterator.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            buf: VecDeque::new(),
+            peek_offset: 0,
+        }
+    }
+
+    /// Pulls items from the inner iterator until the buffer holds `n` items.
+    ///
+    /// Returns `false` if the iterator ran out before that.
+    fn fill(&mut self, n: usize) -> bool {
+        while self.buf.len() < n {
+            match self.iter.next() {
+                Some(x) => self.buf.push_back(x),
+                None => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Returns the next item, taking it from the buffer if it's there.
+    ///
+    /// Resets the peeking cursor.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<I::Item> {
+        self.peek_offset = 0;
+
+        self.buf.pop_front().or_else(|| self.iter.next())
+    }
+
+    /// Peeks the next item in the inner iterator.
+    ///
+    /// Subsequent calls return subsequent items.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0)
+    }
+
+    /// Peek the `n`th value in the iterator.
+    ///
+    /// Already buffered items are not produced again:
+    ///
+    /// ```rust
+    /// # use peeking_iter::BufferedPeekingIter;
+    /// let mut produced = 0;
+    /// let mut it = BufferedPeekingIter::new((0..=9).inspect(|_| produced += 1));
+    ///
+    /// assert_eq!(it.peek_nth(5), Some(&5));
+    /// it.rewind_peeking();
+    /// assert_eq!(it.peek_nth(2), Some(&2));
+    /// it.rewind_peeking();
+    /// assert_eq!(it.peek_nth(4), Some(&4));
+    ///
+    /// drop(it);
+    ///
+    /// assert_eq!(produced, 6);
+    /// ```
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        let idx = self.peek_offset.checked_add(n)?;
+
+        if !self.fill(idx + 1) {
+            self.peek_offset = self.buf.len();
+
+            return None;
+        }
+
+        self.peek_offset = idx + 1;
+
+        self.buf.get(idx)
+    }
+
+    /// Advances the base iterator to the be aligned with the peeking cursor.
+    ///
+    /// ```rust
+    /// # use peeking_iter::BufferedPeekingIter;
+    /// let mut it = BufferedPeekingIter::new(0..=2);
+    ///
+    /// it.peek();
+    /// it.peek();
+    /// it.advance_to_peeked();
+    ///
+    /// assert_eq!(it.next(), Some(2));
+    /// ```
+    pub fn advance_to_peeked(&mut self) {
+        self.buf.drain(..self.peek_offset);
+        self.peek_offset = 0;
+    }
+
+    /// Rewind the peeking cursor to align with the base iterator.
+    pub fn rewind_peeking(&mut self) {
+        self.peek_offset = 0;
+    }
+
+    /// Consumes `self` and returns the inner iterator.
+    ///
+    /// **NOTE:** Any buffered (peeked) items are lost.
+    pub fn into_inner(value: Self) -> I {
+        value.iter
+    }
+
+    /// Consumes `self` and returns the inner iterator and the buffer.
+    pub(crate) fn into_parts(value: Self) -> (I, VecDeque<I::Item>) {
+        (value.iter, value.buf)
+    }
+}
+
+impl<I: Iterator> Iterator for BufferedPeekingIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        BufferedPeekingIter::next(self)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let len = self.buf.len();
+
+        (
+            lo.saturating_add(len),
+            hi.and_then(|hi| hi.checked_add(len)),
+        )
+    }
+}
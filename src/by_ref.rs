@@ -0,0 +1,135 @@
+use crate::BufferedPeekingIter;
+use alloc::collections::vec_deque;
+use core::iter::Chain;
+
+/// Peeking adapter over a borrowed iterator.
+///
+/// Created by [`peeking_by_ref()`](PeekingByRefExt::peeking_by_ref()).
+/// Peeked items are pulled from the borrowed iterator into a buffer, so it
+/// isn't required to implement [`Clone`]. Items are consumed from the
+/// original only as far as the adapter has peeked or consumed.
+///
+/// ```rust
+/// use peeking_iter::PeekingByRefExt;
+///
+/// let mut n = 0;
+/// let mut it = std::iter::from_fn(|| {
+///     n += 1;
+///     (n <= 4).then_some(n)
+/// });
+///
+/// let mut peeking = it.peeking_by_ref();
+///
+/// assert_eq!(peeking.next(), Some(1));
+/// assert_eq!(peeking.peek(), Some(&2));
+/// assert_eq!(peeking.peek(), Some(&3));
+///
+/// // Peeked items that weren't consumed are handed back first
+/// assert_eq!(peeking.into_remaining().collect::<Vec<_>>(), [2, 3, 4]);
+/// ```
+///
+/// **NOTE:** Peeked items are taken out of the borrowed iterator. If the
+/// adapter is dropped without [`into_remaining()`](Self::into_remaining()),
+/// the items it peeked but didn't consume are lost:
+///
+/// ```rust
+/// # use peeking_iter::PeekingByRefExt;
+/// let mut it = 0..=3;
+/// let mut peeking = it.peeking_by_ref();
+///
+/// assert_eq!(peeking.next(), Some(0));
+/// assert_eq!(peeking.peek(), Some(&1));
+/// assert_eq!(peeking.peek(), Some(&2));
+///
+/// drop(peeking);
+///
+/// // `1` and `2` were peeked, but never handed back
+/// assert_eq!(it.next(), Some(3));
+/// ```
+#[must_use = "peeked items are lost unless taken back with `into_remaining()`"]
+pub struct PeekingByRef<'a, I: Iterator> {
+    inner: BufferedPeekingIter<&'a mut I>,
+}
+
+impl<'a, I: Iterator> PeekingByRef<'a, I> {
+    /// Wraps the given iterator.
+    pub fn new(iter: &'a mut I) -> Self {
+        Self {
+            inner: BufferedPeekingIter::new(iter),
+        }
+    }
+
+    /// Returns the next item, taking it from the buffer if it was peeked.
+    ///
+    /// Resets the peeking cursor.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<I::Item> {
+        self.inner.next()
+    }
+
+    /// Peeks the next item in the borrowed iterator.
+    ///
+    /// Subsequent calls return subsequent items.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.inner.peek()
+    }
+
+    /// Peek the `n`th value in the iterator.
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        self.inner.peek_nth(n)
+    }
+
+    /// Consumes the peeked items.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingByRefExt;
+    /// let mut it = 0..=3;
+    /// let mut peeking = it.peeking_by_ref();
+    ///
+    /// peeking.peek();
+    /// peeking.peek();
+    /// peeking.advance_to_peeked();
+    ///
+    /// assert_eq!(peeking.next(), Some(2));
+    /// ```
+    pub fn advance_to_peeked(&mut self) {
+        self.inner.advance_to_peeked();
+    }
+
+    /// Rewind the peeking cursor to align with the consumed items.
+    pub fn rewind_peeking(&mut self) {
+        self.inner.rewind_peeking();
+    }
+
+    /// Consumes the adapter, returning the items it peeked but didn't
+    /// consume, followed by the rest of the borrowed iterator.
+    pub fn into_remaining(self) -> Chain<vec_deque::IntoIter<I::Item>, &'a mut I> {
+        let (iter, buf) = BufferedPeekingIter::into_parts(self.inner);
+
+        buf.into_iter().chain(iter)
+    }
+}
+
+impl<I: Iterator> Iterator for PeekingByRef<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        PeekingByRef::next(self)
+    }
+}
+
+/// Adds [`peeking_by_ref()`](Self::peeking_by_ref()) to all iterators.
+pub trait PeekingByRefExt: Iterator + Sized {
+    /// Borrows the iterator in a [`PeekingByRef`], to peek into it and then
+    /// keep using it.
+    ///
+    /// **NOTE:** Items that were peeked but not consumed are lost, unless
+    /// taken back with
+    /// [`into_remaining()`](PeekingByRef::into_remaining()).
+    #[must_use = "peeked items are lost unless taken back with `into_remaining()`"]
+    fn peeking_by_ref(&mut self) -> PeekingByRef<'_, Self> {
+        PeekingByRef::new(self)
+    }
+}
+
+impl<I: Iterator> PeekingByRefExt for I {}
@@ -0,0 +1,259 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{iter::Copied, slice};
+
+/// What a [`BytesParser`] expected to find.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpectedBytes {
+    /// A specific byte.
+    Byte(u8),
+    /// A specific byte sequence.
+    #[cfg(feature = "alloc")]
+    Bytes(Vec<u8>),
+}
+
+/// An error returned by [`BytesParser`]'s matching helpers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BytesParseError {
+    /// Number of bytes preceding the offending byte.
+    pub offset: usize,
+    /// The offending byte, or `None` at the end of input.
+    pub found: Option<u8>,
+    /// What was expected instead.
+    pub expected: ExpectedBytes,
+}
+
+/// A byte-level counterpart of [`Parser`](crate::Parser), for input that
+/// shouldn't be decoded as UTF-8 up front.
+///
+/// Tracks the byte offset instead of line and column.
+pub struct BytesParser<I>
+where
+    I: Iterator<Item = u8>,
+{
+    iter: I,
+    peeking: Option<I>,
+    peek_offset: usize,
+    offset: usize,
+}
+
+impl<I: Iterator<Item = u8> + Clone> BytesParser<I> {
+    /// Wraps the given iterator.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            peeking: None,
+            peek_offset: 0,
+            offset: 0,
+        }
+    }
+
+    /// Returns the next item in the inner iterator.
+    ///
+    /// Resets the peeking iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<u8> {
+        self.peeking = None;
+        self.peek_offset = 0;
+
+        let next = self.iter.next();
+
+        if next.is_some() {
+            self.offset += 1;
+        }
+
+        next
+    }
+
+    /// Peeks the next item in the inner iterator.
+    ///
+    /// Subsequent calls return subsequent items.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::BytesParser;
+    /// let mut it = BytesParser::from_slice(b"abc");
+    ///
+    /// assert_eq!(it.next(), Some(b'a'));
+    /// assert_eq!(it.peek(), Some(b'b'));
+    /// assert_eq!(it.peek(), Some(b'c'));
+    /// assert_eq!(it.next(), Some(b'b'));
+    /// ```
+    pub fn peek(&mut self) -> Option<u8> {
+        let next = self.peeking.get_or_insert_with(|| self.iter.clone()).next();
+
+        if next.is_some() {
+            self.peek_offset += 1;
+        }
+
+        next
+    }
+
+    /// Peek the `n`th value in the iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::BytesParser;
+    /// let mut it = BytesParser::from_slice(b"abc");
+    ///
+    /// assert_eq!(it.peek_nth(1), Some(b'b'));
+    /// assert_eq!(it.peek_nth(0), Some(b'c'));
+    /// assert_eq!(it.next(), Some(b'a'));
+    /// ```
+    pub fn peek_nth(&mut self, n: usize) -> Option<u8> {
+        for _ in 0..n {
+            self.peek()?;
+        }
+
+        self.peek()
+    }
+
+    /// Advances the base iterator to the be aligned with the peeking one.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::BytesParser;
+    /// let mut it = BytesParser::from_slice(b"abc");
+    ///
+    /// it.peek();
+    /// it.peek();
+    /// it.advance_to_peeked();
+    ///
+    /// assert_eq!(it.offset(), 2);
+    /// assert_eq!(it.next(), Some(b'c'));
+    /// ```
+    pub fn advance_to_peeked(&mut self) {
+        if let Some(peeking) = self.peeking.take() {
+            self.iter = peeking;
+            self.offset += self.peek_offset;
+            self.peek_offset = 0;
+        }
+    }
+
+    /// Rewind the peeking iterator to align with the base one.
+    pub fn rewind_peeking(&mut self) {
+        self.peeking = Some(self.iter.clone());
+        self.peek_offset = 0;
+    }
+
+    /// Returns a `Vec<u8>` containing all continuous bytes that the
+    /// predicate returns `true` for.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::BytesParser;
+    /// let mut it = BytesParser::from_slice(b"12ab");
+    ///
+    /// assert_eq!(it.next_while(|b| b.is_ascii_digit()), b"12");
+    /// assert_eq!(it.next(), Some(b'a'));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn next_while<F: Fn(u8) -> bool>(&mut self, pred: F) -> Vec<u8> {
+        let mut result = Vec::new();
+
+        // If `peeking` had already diverged, bring it back
+        self.rewind_peeking();
+
+        while let Some(b) = self.peek().filter(|b| pred(*b)) {
+            result.push(b);
+            self.next();
+        }
+
+        self.rewind_peeking();
+
+        result
+    }
+
+    /// Consumes the next byte if it is `b`.
+    ///
+    /// Otherwise returns an error and leaves the parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{BytesParser, ExpectedBytes};
+    /// let mut it = BytesParser::from_slice(&[0x7f, 0x45]);
+    ///
+    /// assert_eq!(it.expect(0x7f), Ok(0x7f));
+    ///
+    /// let err = it.expect(0x7f).unwrap_err();
+    ///
+    /// assert_eq!(err.offset, 1);
+    /// assert_eq!(err.found, Some(0x45));
+    /// assert_eq!(err.expected, ExpectedBytes::Byte(0x7f));
+    /// ```
+    pub fn expect(&mut self, b: u8) -> Result<u8, BytesParseError> {
+        self.rewind_peeking();
+
+        match self.peek() {
+            Some(x) if x == b => {
+                self.next();
+                Ok(b)
+            }
+            found => {
+                self.rewind_peeking();
+                Err(self.error(found, ExpectedBytes::Byte(b)))
+            }
+        }
+    }
+
+    /// Consumes `bytes` if the input starts with them.
+    ///
+    /// Otherwise returns an error at the current offset, with `found` being
+    /// the first byte that doesn't match, and leaves the parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::BytesParser;
+    /// let mut it = BytesParser::from_slice(b"\x89PNG\r\n");
+    ///
+    /// assert!(it.expect_bytes(b"GIF").is_err());
+    /// assert_eq!(it.expect_bytes(b"\x89PNG"), Ok(()));
+    /// assert_eq!(it.offset(), 4);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn expect_bytes(&mut self, bytes: &[u8]) -> Result<(), BytesParseError> {
+        self.rewind_peeking();
+
+        for &b in bytes {
+            match self.peek() {
+                Some(x) if x == b => (),
+                found => {
+                    self.rewind_peeking();
+                    return Err(self.error(found, ExpectedBytes::Bytes(bytes.into())));
+                }
+            }
+        }
+
+        self.advance_to_peeked();
+
+        Ok(())
+    }
+
+    /// Consumes `self` and returns the inner (base) iterator.
+    pub fn into_inner(value: Self) -> I {
+        value.iter
+    }
+
+    /// Returns the number of bytes consumed so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Creates an error at the current offset.
+    fn error(&self, found: Option<u8>, expected: ExpectedBytes) -> BytesParseError {
+        BytesParseError {
+            offset: self.offset,
+            found,
+            expected,
+        }
+    }
+}
+
+impl<'a> BytesParser<Copied<slice::Iter<'a, u8>>> {
+    /// Wraps the bytes of `bytes`.
+    pub fn from_slice(bytes: &'a [u8]) -> Self {
+        Self::new(bytes.iter().copied())
+    }
+}
+
+impl<I: Iterator<Item = u8> + Clone> Iterator for BytesParser<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        BytesParser::next(self)
+    }
+}
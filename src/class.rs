@@ -0,0 +1,94 @@
+use crate::parser::CharPattern;
+use core::{fmt, ops::RangeInclusive};
+
+/// A set of characters, made up of ranges and individual characters.
+///
+/// Can be built in a `const` context, with ASCII membership precomputed.
+///
+/// ```rust
+/// # use peeking_iter::parser::CharClass;
+/// const IDENT: CharClass = CharClass::new(&['a'..='z', 'A'..='Z'], &['_']);
+///
+/// assert!(IDENT.contains('q'));
+/// assert!(IDENT.contains('_'));
+/// assert!(!IDENT.contains('1'));
+/// assert_eq!(IDENT.to_string(), "[a-zA-Z_]");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharClass {
+    ranges: &'static [RangeInclusive<char>],
+    chars: &'static [char],
+    ascii: u128,
+}
+
+impl CharClass {
+    /// Creates a class of the characters in `ranges` and `chars`.
+    pub const fn new(ranges: &'static [RangeInclusive<char>], chars: &'static [char]) -> Self {
+        let mut ascii = 0;
+        let mut i = 0;
+
+        while i < ranges.len() {
+            let mut c = *ranges[i].start() as u32;
+
+            while c <= *ranges[i].end() as u32 && c < 128 {
+                ascii |= 1 << c;
+                c += 1;
+            }
+
+            i += 1;
+        }
+
+        i = 0;
+
+        while i < chars.len() {
+            if (chars[i] as u32) < 128 {
+                ascii |= 1 << chars[i] as u32;
+            }
+
+            i += 1;
+        }
+
+        Self {
+            ranges,
+            chars,
+            ascii,
+        }
+    }
+
+    /// Returns `true` if `c` is in the class.
+    pub fn contains(&self, c: char) -> bool {
+        if c.is_ascii() {
+            self.ascii & (1 << c as u32) != 0
+        } else {
+            self.ranges.iter().any(|r| r.contains(&c)) || self.chars.contains(&c)
+        }
+    }
+}
+
+impl CharPattern for CharClass {
+    fn matches(&self, c: char) -> bool {
+        self.contains(c)
+    }
+}
+
+impl CharPattern for &CharClass {
+    fn matches(&self, c: char) -> bool {
+        self.contains(c)
+    }
+}
+
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+
+        for r in self.ranges {
+            write!(f, "{}-{}", r.start().escape_debug(), r.end().escape_debug())?;
+        }
+
+        for c in self.chars {
+            write!(f, "{}", c.escape_debug())?;
+        }
+
+        f.write_str("]")
+    }
+}
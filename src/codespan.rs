@@ -0,0 +1,47 @@
+use crate::parser::{ErrorKind, ParseError};
+use alloc::{format, vec};
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+impl ParseError {
+    /// Converts the error into a [`Diagnostic`] labeling the offending
+    /// character in the file `file_id`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::Parser;
+    /// use codespan_reporting::diagnostic::Diagnostic;
+    ///
+    /// let mut it = Parser::new("(x".chars());
+    ///
+    /// it.next();
+    ///
+    /// let diagnostic = it.expect(')').unwrap_err().to_diagnostic(0);
+    ///
+    /// assert_eq!(diagnostic.message, "expected ')', found 'x'");
+    /// assert_eq!(diagnostic.labels[0].range, 1..2);
+    /// ```
+    pub fn to_diagnostic<F>(&self, file_id: F) -> Diagnostic<F> {
+        let start = self.position.offset;
+        let range = start..start + self.found.map_or(0, char::len_utf8);
+        let found = match self.found {
+            Some(c) => format!("{c:?}"),
+            None => "end of input".into(),
+        };
+
+        let message = match self.kind {
+            ErrorKind::Custom => format!("{}", self.expected),
+            _ => format!("expected {}, found {found}", self.expected),
+        };
+
+        Diagnostic::error().with_message(message).with_labels(vec![
+            Label::primary(file_id, range).with_message(format!("expected {}", self.expected))
+        ])
+    }
+}
+
+impl From<ParseError> for Diagnostic<()> {
+    /// Converts the error into a [`Diagnostic`] for a single-file source,
+    /// such as [`SimpleFile`](codespan_reporting::files::SimpleFile).
+    fn from(value: ParseError) -> Self {
+        value.to_diagnostic(())
+    }
+}
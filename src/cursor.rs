@@ -0,0 +1,66 @@
+/// An independent peek cursor over a [`PeekingIter`](crate::PeekingIter).
+///
+/// Created by [`PeekingIter::cursor()`](crate::PeekingIter::cursor()).
+/// Advancing a cursor doesn't affect the base iterator, its peeking
+/// iterator or any other cursor.
+///
+/// Use [`PeekingIter::commit()`](crate::PeekingIter::commit()) to advance
+/// the base iterator to the cursor's position.
+#[derive(Clone, Debug)]
+pub struct Cursor<I> {
+    iter: I,
+    offset: usize,
+    base: usize,
+}
+
+impl<I: Iterator> Cursor<I> {
+    /// Creates a cursor at a base iterator that has consumed `base` items.
+    pub(crate) fn new(iter: I, base: usize) -> Self {
+        Self {
+            iter,
+            offset: 0,
+            base,
+        }
+    }
+
+    /// Returns the number of items the cursor has advanced past the base
+    /// iterator it was created from.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new(0..=2);
+    /// let mut cursor = it.cursor();
+    ///
+    /// cursor.next();
+    /// cursor.next();
+    ///
+    /// assert_eq!(cursor.offset(), 2);
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of items the base iterator had consumed when the
+    /// cursor was created.
+    pub(crate) fn base(&self) -> usize {
+        self.base
+    }
+
+    pub(crate) fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for Cursor<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let next = self.iter.next();
+
+        if next.is_some() {
+            self.offset += 1;
+        }
+
+        next
+    }
+}
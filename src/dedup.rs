@@ -0,0 +1,39 @@
+use crate::PeekingIter;
+
+/// Iterator that drops consecutive duplicate items.
+///
+/// Created by [`PeekingIter::dedup_by()`].
+#[derive(Clone)]
+pub struct DedupBy<I: Iterator, F> {
+    iter: PeekingIter<I>,
+    eq: F,
+}
+
+/// Iterator that drops consecutive equal items.
+///
+/// Created by [`PeekingIter::dedup()`].
+pub type Dedup<I> = DedupBy<I, fn(&<I as Iterator>::Item, &<I as Iterator>::Item) -> bool>;
+
+impl<I: Iterator + Clone, F> DedupBy<I, F> {
+    pub(crate) fn new(iter: PeekingIter<I>, eq: F) -> Self {
+        Self { iter, eq }
+    }
+}
+
+impl<I, F> Iterator for DedupBy<I, F>
+where
+    I: Iterator + Clone,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let x = self.iter.next()?;
+
+        while self.iter.peek_ref().is_some_and(|y| (self.eq)(&x, y)) {
+            self.iter.next();
+        }
+
+        Some(x)
+    }
+}
@@ -0,0 +1,166 @@
+use crate::PeekStrategy;
+
+/// Iterator adapter that allows peeking up to `N` items ahead, keeping them
+/// in an inline array.
+///
+/// Requires neither [`Clone`] on the inner iterator nor an allocator, which
+/// makes it suitable for LL(k) parsers on embedded targets. Peeking deeper
+/// than `N` items returns [`None`].
+///
+/// Peeked items are returned by reference.
+///
+/// ```rust
+/// # use peeking_iter::PeekingIterN;
+/// let mut it = PeekingIterN::<_, 2>::new(0..=3);
+///
+/// assert_eq!(it.next(), Some(0));
+/// assert_eq!(it.peek(), Some(&1));
+/// assert_eq!(it.peek(), Some(&2));
+/// assert_eq!(it.peek(), None); // Too deep
+/// assert_eq!(it.next(), Some(1));
+/// assert_eq!(it.peek_nth(1), Some(&3));
+/// ```
+pub struct PeekingIterN<I: Iterator, const N: usize> {
+    iter: I,
+    buf: [Option<I::Item>; N],
+    start: usize,
+    len: usize,
+    peek_offset: usize,
+}
+
+impl<I: Iterator, const N: usize> PeekingIterN<I, N> {
+    /// Wraps the given iterator.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            buf: core::array::from_fn(|_| None),
+            start: 0,
+            len: 0,
+            peek_offset: 0,
+        }
+    }
+
+    /// Returns the maximum lookahead depth (`N`).
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the next item, taking it from the buffer if it's there.
+    ///
+    /// Resets the peeking cursor.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<I::Item> {
+        self.peek_offset = 0;
+
+        if self.len == 0 {
+            return self.iter.next();
+        }
+
+        let x = self.buf[self.start].take();
+
+        self.start = (self.start + 1) % N;
+        self.len -= 1;
+
+        x
+    }
+
+    /// Peeks the next item in the inner iterator.
+    ///
+    /// Subsequent calls return subsequent items.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0)
+    }
+
+    /// Peek the `n`th value in the iterator.
+    ///
+    /// Returns [`None`] if that's deeper than `N` items.
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        let idx = self.peek_offset.checked_add(n)?;
+
+        if idx >= N {
+            return None;
+        }
+
+        while self.len <= idx {
+            let x = self.iter.next()?;
+
+            self.buf[(self.start + self.len) % N] = Some(x);
+            self.len += 1;
+        }
+
+        self.peek_offset = idx + 1;
+
+        self.buf[(self.start + idx) % N].as_ref()
+    }
+
+    /// Advances the base iterator to the be aligned with the peeking cursor.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIterN;
+    /// let mut it = PeekingIterN::<_, 2>::new(0..=2);
+    ///
+    /// it.peek();
+    /// it.peek();
+    /// it.advance_to_peeked();
+    ///
+    /// assert_eq!(it.next(), Some(2));
+    /// ```
+    pub fn advance_to_peeked(&mut self) {
+        for _ in 0..self.peek_offset {
+            self.next();
+        }
+
+        self.peek_offset = 0;
+    }
+
+    /// Rewind the peeking cursor to align with the base iterator.
+    pub fn rewind_peeking(&mut self) {
+        self.peek_offset = 0;
+    }
+
+    /// Consumes `self` and returns the inner iterator.
+    ///
+    /// **NOTE:** Any buffered (peeked) items are lost.
+    pub fn into_inner(value: Self) -> I {
+        value.iter
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for PeekingIterN<I, N> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        PeekingIterN::next(self)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+
+        (
+            lo.saturating_add(self.len),
+            hi.and_then(|hi| hi.checked_add(self.len)),
+        )
+    }
+}
+
+/// Peeked items are cloned out of the buffer.
+impl<I: Iterator, const N: usize> PeekStrategy for PeekingIterN<I, N>
+where
+    I::Item: Clone,
+{
+    fn peek(&mut self) -> Option<I::Item> {
+        PeekingIterN::peek(self).cloned()
+    }
+
+    fn peek_nth(&mut self, n: usize) -> Option<I::Item> {
+        PeekingIterN::peek_nth(self, n).cloned()
+    }
+
+    fn advance_to_peeked(&mut self) {
+        PeekingIterN::advance_to_peeked(self)
+    }
+
+    fn rewind_peeking(&mut self) {
+        PeekingIterN::rewind_peeking(self)
+    }
+}
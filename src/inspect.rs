@@ -0,0 +1,104 @@
+use crate::PeekingIter;
+
+/// Adapter calling a closure on every peeked item.
+///
+/// Created by [`PeekingIter::inspect_peek()`]. The closure receives the
+/// item and its lookahead offset (`0` being the item right after the base
+/// iterator).
+///
+/// Unlike [`Iterator::inspect()`], it only sees peeked items, not consumed
+/// ones.
+pub struct InspectPeek<I: Iterator, F> {
+    iter: PeekingIter<I>,
+    f: F,
+    offset: usize,
+}
+
+impl<I, F> InspectPeek<I, F>
+where
+    I: Iterator + Clone,
+    F: FnMut(&I::Item, usize),
+{
+    pub(crate) fn new(iter: PeekingIter<I>, f: F) -> Self {
+        Self { iter, f, offset: 0 }
+    }
+
+    /// Returns the next item in the inner iterator.
+    ///
+    /// Resets the peeking iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<I::Item> {
+        self.offset = 0;
+
+        self.iter.next()
+    }
+
+    /// Peeks the next item in the inner iterator, calling the closure on
+    /// it.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut seen = vec![];
+    /// let mut it = PeekingIter::new(0..=2).inspect_peek(|x, offset| seen.push((*x, offset)));
+    ///
+    /// it.next();
+    /// it.peek();
+    /// it.peek();
+    /// it.rewind_peeking();
+    /// it.peek();
+    ///
+    /// drop(it);
+    ///
+    /// assert_eq!(seen, vec![(1, 0), (2, 1), (1, 0)]);
+    /// ```
+    pub fn peek(&mut self) -> Option<I::Item> {
+        let item = self.iter.peek()?;
+
+        (self.f)(&item, self.offset);
+        self.offset += 1;
+
+        Some(item)
+    }
+
+    /// Peek the `n`th value in the iterator.
+    ///
+    /// The closure is called on every item on the way.
+    pub fn peek_nth(&mut self, n: usize) -> Option<I::Item> {
+        for _ in 0..n {
+            self.peek()?;
+        }
+
+        self.peek()
+    }
+
+    /// Advances the base iterator to the be aligned with the peeking one.
+    pub fn advance_to_peeked(&mut self) {
+        self.offset = 0;
+
+        self.iter.advance_to_peeked();
+    }
+
+    /// Rewind the peeking iterator to align with the base one.
+    pub fn rewind_peeking(&mut self) {
+        self.offset = 0;
+
+        self.iter.rewind_peeking();
+    }
+
+    /// Consumes `self` and returns the wrapped [`PeekingIter`].
+    pub fn into_inner(value: Self) -> PeekingIter<I> {
+        value.iter
+    }
+}
+
+impl<I, F> Iterator for InspectPeek<I, F>
+where
+    I: Iterator + Clone,
+    F: FnMut(&I::Item, usize),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        InspectPeek::next(self)
+    }
+}
@@ -1,3 +1,24 @@
+#[cfg(feature = "stats")]
+use crate::PeekStats;
+#[cfg(feature = "peek-protection")]
+use crate::{protection::RepeatPeekProtection, RepeatPeekAction};
+use crate::{
+    ArrayWindows, Batches, Cursor, Dedup, DedupBy, InspectPeek, MergeSorted, Outcome, Pairs,
+    Triples, ZipPeek,
+};
+#[cfg(feature = "alloc")]
+use crate::{RunsByKey, SplitWhen, Windows};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(any(feature = "stats", feature = "peek-protection"))]
+use core::cell::Cell;
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::{Chain, Filter, Fuse, Map},
+    num::NonZeroUsize,
+};
+
 /// Iterator adapter that enables infinitely-deep peeking.
 ///
 /// First call to [`peek()`] returns the next element, further calls
@@ -9,15 +30,56 @@
 /// It you don't call [`peek()`] at all, this is just as performant as
 /// the original iterator.
 ///
+/// Alternating [`next()`](PeekingIter::next()) with a single [`peek()`]
+/// doesn't clone the inner iterator after the first time.
+///
 /// This adapter is ~1.5x faster than [`itertools::MultiPeek`] (see
 /// `/benches/bench.rs`).
 ///
+/// Every new peek sequence re-walks a clone of the base iterator. For
+/// grammars doing heavy repeated lookahead, consider
+/// [`BufferedPeekingIter`](crate::BufferedPeekingIter), which caches the
+/// peeked items instead.
+///
 /// [`peek()`]: PeekingIter::peek()
 /// [`itertools::MultiPeek`]:
 /// https://docs.rs/itertools/latest/itertools/structs/struct.MultiPeek.html
+///
+/// # Serialization
+/// With the `serde` feature enabled, [`PeekingIter`] implements
+/// `Serialize`/`Deserialize` whenever the inner iterator does, including
+/// the peeking state:
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// # use peeking_iter::PeekingIter;
+/// let mut it = PeekingIter::new(0..5);
+///
+/// it.next();
+/// it.peek();
+///
+/// let json = serde_json::to_string(&it).unwrap();
+/// let mut it: PeekingIter<std::ops::Range<i32>> = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(it.peek(), Some(2));
+/// assert_eq!(it.next(), Some(1));
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PeekingIter<I: Iterator> {
     iter: I,
     peeking: Option<I>,
+    peek_offset: usize,
+    peeking_back: Option<I>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    peeked: Option<Option<I::Item>>,
+    consumed: usize,
+    #[cfg(feature = "stats")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    stats: Cell<PeekStats>,
+    #[cfg(feature = "peek-protection")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    protection: Cell<RepeatPeekProtection>,
 }
 
 impl<I: Iterator + Clone> PeekingIter<I> {
@@ -26,16 +88,107 @@ impl<I: Iterator + Clone> PeekingIter<I> {
         Self {
             iter,
             peeking: None,
+            peek_offset: 0,
+            peeking_back: None,
+            peeked: None,
+            consumed: 0,
+            #[cfg(feature = "stats")]
+            stats: Cell::default(),
+            #[cfg(feature = "peek-protection")]
+            protection: Cell::default(),
         }
     }
 
+    /// Wraps the given iterator, fusing it first.
+    ///
+    /// Guarantees that once [`peek()`](Self::peek()) returns [`None`],
+    /// [`next()`](Self::next()) does too, even if `iter` isn't
+    /// [`FusedIterator`](core::iter::FusedIterator).
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// // Yields `None` on every other call
+    /// #[derive(Clone)]
+    /// struct Blinking(u8);
+    ///
+    /// impl Iterator for Blinking {
+    ///     type Item = u8;
+    ///
+    ///     fn next(&mut self) -> Option<u8> {
+    ///         self.0 += 1;
+    ///         (self.0 % 2 == 0).then_some(self.0)
+    ///     }
+    /// }
+    ///
+    /// let mut it = PeekingIter::fused(Blinking(0));
+    ///
+    /// assert_eq!(it.peek(), None);
+    /// assert_eq!(it.next(), None);
+    /// assert_eq!(it.next(), None);
+    /// ```
+    pub fn fused(iter: I) -> PeekingIter<Fuse<I>> {
+        PeekingIter::new(iter.fuse())
+    }
+
+    /// Enables detection of the same lookahead region being re-scanned
+    /// more than `limit` times without the base iterator advancing.
+    ///
+    /// Helps to find accidental O(n²) peek loops.
+    ///
+    /// ```rust,should_panic
+    /// # use peeking_iter::{PeekingIter, RepeatPeekAction};
+    /// let mut it = PeekingIter::new(0..=2)
+    ///     .repeat_peek_protection(1, RepeatPeekAction::Panic);
+    ///
+    /// it.peek();
+    /// it.rewind_peeking();
+    /// it.peek();
+    /// it.rewind_peeking();
+    /// it.peek(); // Panics
+    /// ```
+    ///
+    /// Any lookahead from the base iterator counts as a scan, not only
+    /// [`peek()`](Self::peek()):
+    ///
+    /// ```rust,should_panic
+    /// # use peeking_iter::{PeekingIter, RepeatPeekAction};
+    /// let it = PeekingIter::new(0..=2)
+    ///     .repeat_peek_protection(1, RepeatPeekAction::Panic);
+    ///
+    /// it.starts_with(&[1]);
+    /// it.position_of(|x| *x == 2);
+    /// it.cursor(); // Panics
+    /// ```
+    #[cfg(feature = "peek-protection")]
+    pub fn repeat_peek_protection(mut self, limit: usize, action: RepeatPeekAction) -> Self {
+        self.protection.get_mut().set(limit, action);
+
+        self
+    }
+
     /// Returns the next item in the inner iterator.
     ///
     /// Resets the peeking iterator.
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Option<I::Item> {
-        self.peeking = None;
+        // If only one item was peeked, the peeking iterator ends up exactly
+        // where the base one will be, so it can be reused instead of
+        // cloning the base again on the next `peek()`
+        let reusable = match self.peek_offset {
+            1 => self.peeking.take(),
+            _ => None,
+        };
+
+        self.rewind_peeking();
+
+        let next = self.iter.next();
+
+        if next.is_some() {
+            self.advanced(1);
+            self.peeking = reusable;
+        }
 
-        self.iter.next()
+        next
     }
 
     /// Peeks the next item in the inner iterator.
@@ -54,7 +207,136 @@ impl<I: Iterator + Clone> PeekingIter<I> {
     /// assert_eq!(it.peek(), None);
     /// ```
     pub fn peek(&mut self) -> Option<I::Item> {
-        self.peeking.get_or_insert_with(|| self.iter.clone()).next()
+        if self.peeking.is_none() {
+            self.peeking = Some(self.scan_base());
+        }
+
+        #[cfg(feature = "stats")]
+        self.record(|stats| stats.peeks += 1);
+
+        let next = self.peeking.as_mut().and_then(Iterator::next);
+
+        if next.is_some() {
+            self.peek_offset += 1;
+        }
+
+        next
+    }
+
+    /// Returns a reference to the next item in the inner iterator, without
+    /// advancing either the base or the peeking iterator.
+    ///
+    /// The item is stored internally, so repeated calls don't produce it
+    /// again and items don't need to implement [`Clone`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Token(u32);
+    ///
+    /// let mut it = PeekingIter::new((0..=2).map(Token));
+    ///
+    /// assert_eq!(it.peek_ref(), Some(&Token(0)));
+    /// assert_eq!(it.peek_ref(), Some(&Token(0)));
+    /// assert_eq!(it.next(), Some(Token(0)));
+    /// assert_eq!(it.peek_ref(), Some(&Token(1)));
+    /// ```
+    pub fn peek_ref(&mut self) -> Option<&I::Item> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.scan_base().next());
+        }
+
+        #[cfg(feature = "stats")]
+        self.record(|stats| stats.peeks += 1);
+
+        self.peeked.as_ref().and_then(Option::as_ref)
+    }
+
+    /// Returns `true` if the next item (right after the base iterator)
+    /// matches the predicate.
+    ///
+    /// Doesn't disturb the peeking iterator, see
+    /// [`peek_ref()`](Self::peek_ref()).
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=2);
+    ///
+    /// assert_eq!(it.peek(), Some(0));
+    /// assert_eq!(it.peek(), Some(1));
+    /// assert!(it.is_next(|x| *x == 0));
+    /// assert_eq!(it.peek(), Some(2));
+    /// ```
+    pub fn is_next<F: FnOnce(&I::Item) -> bool>(&mut self, pred: F) -> bool {
+        self.peek_ref().is_some_and(pred)
+    }
+
+    /// Returns `true` if the next item (right after the base iterator) is
+    /// equal to `value`.
+    ///
+    /// Doesn't disturb the peeking iterator, see
+    /// [`peek_ref()`](Self::peek_ref()).
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new("ab".chars());
+    ///
+    /// assert!(it.next_matches(&'a'));
+    /// assert!(!it.next_matches(&'b'));
+    /// ```
+    pub fn next_matches(&mut self, value: &I::Item) -> bool
+    where
+        I::Item: PartialEq,
+    {
+        self.peek_ref() == Some(value)
+    }
+
+    /// Returns `true` if the upcoming items (starting at the base iterator)
+    /// are equal to `prefix`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new(0..=3);
+    ///
+    /// assert!(it.starts_with(&[0, 1]));
+    /// assert!(!it.starts_with(&[1, 2]));
+    /// ```
+    pub fn starts_with(&self, prefix: &[I::Item]) -> bool
+    where
+        I::Item: PartialEq,
+    {
+        let mut ahead = self.scan_base();
+
+        prefix.iter().all(|x| ahead.next().as_ref() == Some(x))
+    }
+
+    /// Consumes the upcoming items if they are equal to `prefix`.
+    ///
+    /// Returns `true` if they were consumed. Otherwise, nothing is changed.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=3);
+    ///
+    /// assert!(!it.consume_prefix(&[1, 2]));
+    /// assert!(it.consume_prefix(&[0, 1]));
+    /// assert_eq!(it.next(), Some(2));
+    /// ```
+    pub fn consume_prefix(&mut self, prefix: &[I::Item]) -> bool
+    where
+        I::Item: PartialEq,
+    {
+        let mut ahead = self.scan_base();
+
+        if !prefix.iter().all(|x| ahead.next().as_ref() == Some(x)) {
+            return false;
+        }
+
+        self.peeking = Some(ahead);
+        self.peek_offset = prefix.len();
+        self.advance_to_peeked();
+
+        true
     }
 
     /// Peek the `n`th value in the iterator.
@@ -65,14 +347,156 @@ impl<I: Iterator + Clone> PeekingIter<I> {
     ///
     /// assert_eq!(it.peek_nth(2), Some(2));
     /// assert_eq!(it.next(), Some(0));
+    /// assert_eq!(it.peek_nth(2), None);
     /// ```
     pub fn peek_nth(&mut self, n: usize) -> Option<I::Item> {
-        n.checked_add(1)
-            .and_then(|n1| (0..n1).flat_map(|_| self.peek()).last())
+        for _ in 0..n {
+            self.peek()?;
+        }
+
+        self.peek()
+    }
+
+    /// Consumes `n` items.
+    ///
+    /// Returns `Err(k)` if the iterator ran out early, where `k` is the
+    /// number of items that were missing (like the unstable
+    /// [`Iterator::advance_by()`]).
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// # use std::num::NonZeroUsize;
+    /// let mut it = PeekingIter::new(0..=3);
+    ///
+    /// assert_eq!(it.try_advance_by(2), Ok(()));
+    /// assert_eq!(it.next(), Some(2));
+    /// assert_eq!(it.try_advance_by(3), Err(NonZeroUsize::new(2).unwrap()));
+    /// ```
+    pub fn try_advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        if n == 0 {
+            return Ok(());
+        }
+
+        let before = self.consumed;
+
+        self.nth(n - 1);
+
+        NonZeroUsize::new(n - (self.consumed - before)).map_or(Ok(()), Err)
+    }
+
+    /// Peeks the `n`th item and, if it matches the predicate, consumes
+    /// everything up to and including it.
+    ///
+    /// Returns the `n`th item if it was consumed. Otherwise, neither the
+    /// base nor the peeking iterator are changed.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new("foo ( )".split(' '));
+    ///
+    /// assert_eq!(it.nth_if(1, |tok| *tok == "="), None);
+    /// assert_eq!(it.nth_if(1, |tok| *tok == "("), Some("("));
+    /// assert_eq!(it.next(), Some(")"));
+    /// ```
+    pub fn nth_if<F: FnOnce(&I::Item) -> bool>(&mut self, n: usize, pred: F) -> Option<I::Item> {
+        let mut peeking = self.scan_base();
+
+        match peeking.nth(n) {
+            Some(x) if pred(&x) => {
+                self.peeking = Some(peeking);
+                self.peek_offset = n + 1;
+                self.advance_to_peeked();
+
+                Some(x)
+            }
+            _ => None,
+        }
+    }
+
+    /// Peeks ahead (starting at the base iterator) until an item matches
+    /// the predicate and returns it.
+    ///
+    /// On success, the peeking iterator is left right after the found item,
+    /// so [`advance_to_peeked()`](Self::advance_to_peeked()) consumes
+    /// through it. Otherwise, it is rewound.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=4);
+    ///
+    /// assert_eq!(it.peek_find(|x| *x > 2), Some(3));
+    /// assert_eq!(it.peek(), Some(4));
+    /// assert_eq!(it.peek_find(|x| *x > 5), None);
+    /// assert_eq!(it.next(), Some(0));
+    /// ```
+    pub fn peek_find<F: FnMut(&I::Item) -> bool>(&mut self, mut pred: F) -> Option<I::Item> {
+        self.rewind_peeking();
+
+        while let Some(x) = self.peek() {
+            if pred(&x) {
+                return Some(x);
+            }
+        }
+
+        self.rewind_peeking();
+
+        None
+    }
+
+    /// Returns the offset (from the base iterator) of the first item that
+    /// matches the predicate, without advancing either the base or the
+    /// peeking iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new("ab;c".chars());
+    ///
+    /// it.next();
+    ///
+    /// assert_eq!(it.position_of(|c| *c == ';'), Some(1));
+    /// assert_eq!(it.position_of(|c| *c == '!'), None);
+    /// ```
+    pub fn position_of<F: FnMut(&I::Item) -> bool>(&self, mut pred: F) -> Option<usize> {
+        self.scan_base().position(|x| pred(&x))
+    }
+
+    /// Returns all remaining items, without advancing either the base or the
+    /// peeking iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=3);
+    ///
+    /// it.next();
+    /// it.peek();
+    ///
+    /// assert_eq!(it.peek_rest(), vec![1, 2, 3]);
+    /// assert_eq!(it.peek(), Some(2));
+    /// assert_eq!(it.next(), Some(1));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn peek_rest(&self) -> Vec<I::Item> {
+        self.scan_base().collect()
+    }
+
+    /// Returns the last remaining item, without advancing either the base or
+    /// the peeking iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new("a b ;".split(' '));
+    ///
+    /// assert_eq!(it.peek_last(), Some(";"));
+    /// assert_eq!(it.next(), Some("a"));
+    /// ```
+    pub fn peek_last(&self) -> Option<I::Item> {
+        self.scan_base().last()
     }
 
     /// Advances the base iterator to the be aligned with the peeking one.
     ///
+    /// The peeking iterator is moved into the base, so no extra clone is made.
+    ///
     /// ```rust
     /// # use peeking_iter::PeekingIter;
     /// let mut it = PeekingIter::new(0..=2);
@@ -86,11 +510,40 @@ impl<I: Iterator + Clone> PeekingIter<I> {
     /// assert_eq!(it.next(), None);
     /// ```
     pub fn advance_to_peeked(&mut self) {
-        if let Some(ref peeking) = self.peeking {
-            self.iter = peeking.clone();
+        if let Some(peeking) = self.peeking.take() {
+            let n = self.peek_offset;
+
+            self.iter = peeking;
+            self.rewind_peeking();
+            self.advanced(n);
+
+            #[cfg(feature = "stats")]
+            self.record(|stats| stats.commits += 1);
         }
     }
 
+    /// Like [`advance_to_peeked()`](Self::advance_to_peeked()), but returns
+    /// the items that were skipped over.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new("a b ; c".split(' '));
+    ///
+    /// it.peek_find(|tok| *tok == ";");
+    ///
+    /// assert_eq!(it.commit_peeked(), vec!["a", "b", ";"]);
+    /// assert_eq!(it.next(), Some("c"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn commit_peeked(&mut self) -> Vec<I::Item> {
+        let n = self.peek_offset;
+        let result = (0..n).map_while(|_| self.next()).collect();
+
+        self.rewind_peeking();
+
+        result
+    }
+
     /// Rewind the peeking iterator to align with the base one.
     ///
     /// ```rust
@@ -105,7 +558,110 @@ impl<I: Iterator + Clone> PeekingIter<I> {
     /// assert_eq!(it.peek(), Some(0));
     /// ```
     pub fn rewind_peeking(&mut self) {
+        #[cfg(feature = "stats")]
+        if self.peeking.is_some() {
+            self.record(|stats| stats.rewinds += 1);
+        }
+
         self.peeking = None;
+        self.peek_offset = 0;
+        self.peeking_back = None;
+        self.peeked = None;
+    }
+
+    /// Opens a new peek cursor, starting at the base iterator.
+    ///
+    /// Cursors advance independently of each other and of
+    /// [`peek()`](Self::peek()).
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=3);
+    /// let mut c1 = it.cursor();
+    /// let mut c2 = it.cursor();
+    ///
+    /// assert_eq!(c1.next(), Some(0));
+    /// assert_eq!(c1.next(), Some(1));
+    /// assert_eq!(c2.next(), Some(0));
+    /// assert_eq!(it.peek(), Some(0));
+    ///
+    /// it.commit(c1).unwrap();
+    ///
+    /// assert_eq!(it.next(), Some(2));
+    /// ```
+    pub fn cursor(&self) -> Cursor<I> {
+        Cursor::new(self.scan_base(), self.consumed)
+    }
+
+    /// Advances the base iterator to the position of the given cursor.
+    ///
+    /// Resets the peeking iterator.
+    ///
+    /// Returns the cursor back as an error if the base iterator has moved
+    /// since it was created, as committing it would move the base backwards.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=3);
+    /// let mut cursor = it.cursor();
+    ///
+    /// cursor.next();
+    /// it.next();
+    /// it.next();
+    ///
+    /// assert!(it.commit(cursor).is_err());
+    /// assert_eq!(it.items_consumed(), 2);
+    /// assert_eq!(it.next(), Some(2));
+    /// ```
+    pub fn commit(&mut self, cursor: Cursor<I>) -> Result<(), Cursor<I>> {
+        if cursor.base() != self.consumed {
+            return Err(cursor);
+        }
+
+        let n = cursor.offset();
+
+        self.rewind_peeking();
+        self.iter = cursor.into_inner();
+        self.advanced(n);
+
+        #[cfg(feature = "stats")]
+        self.record(|stats| stats.commits += 1);
+
+        Ok(())
+    }
+
+    /// Runs `f` against `self`, keeping everything it consumed only if it
+    /// succeeds (returns `Some`/`Ok`).
+    ///
+    /// Otherwise the base iterator is restored to where it was before the
+    /// call.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=3);
+    ///
+    /// let res = it.speculate(|it| it.next().filter(|x| *x == 1));
+    ///
+    /// assert_eq!(res, None);
+    /// assert_eq!(it.next(), Some(0));
+    ///
+    /// let res: Result<_, ()> = it.speculate(|it| Ok((it.next(), it.next())));
+    ///
+    /// assert_eq!(res, Ok((Some(1), Some(2))));
+    /// assert_eq!(it.next(), Some(3));
+    /// ```
+    pub fn speculate<R: Outcome, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let saved = self.clone_base();
+        let consumed = self.consumed;
+        let result = f(self);
+
+        if !result.is_success() {
+            self.iter = saved;
+            self.consumed = consumed;
+            self.rewind_peeking();
+        }
+
+        result
     }
 
     /// Returns a `Vec<I::Item>` containing all continuous elements that the
@@ -119,9 +675,37 @@ impl<I: Iterator + Clone> PeekingIter<I> {
     /// assert_eq!(it.peek(), Some(2));
     /// assert_eq!(it.next(), Some(2));
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn next_while<F: Fn(&I::Item) -> bool>(&mut self, pred: F) -> Vec<I::Item> {
         let mut result = vec![];
 
+        self.next_while_into(&mut result, pred);
+
+        result
+    }
+
+    /// Like [`next_while()`](Self::next_while()), but appends the elements to
+    /// `buf` instead of allocating a new `Vec`.
+    ///
+    /// Returns the number of elements appended.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=3);
+    /// let mut buf = vec![9];
+    ///
+    /// assert_eq!(it.next_while_into(&mut buf, |x| *x < 2), 2);
+    /// assert_eq!(buf, vec![9, 0, 1]);
+    /// assert_eq!(it.next(), Some(2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn next_while_into<F: Fn(&I::Item) -> bool>(
+        &mut self,
+        buf: &mut Vec<I::Item>,
+        pred: F,
+    ) -> usize {
+        let len = buf.len();
+
         // If `peeking` had already diverged, bring it back
         self.rewind_peeking();
 
@@ -130,7 +714,7 @@ impl<I: Iterator + Clone> PeekingIter<I> {
                 None => break,
                 Some(x) => {
                     if pred(&x) {
-                        result.push(x);
+                        buf.push(x);
                         self.next();
                     } else {
                         break;
@@ -141,14 +725,49 @@ impl<I: Iterator + Clone> PeekingIter<I> {
 
         self.rewind_peeking();
 
-        result
+        buf.len() - len
     }
 
-    /// Like [`next_while()`](Self::next_while()), except consumes the first
-    /// element that doesn't suffice (without returning it).
+    /// Consumes items while `f` returns [`Some`], threading mutable `state`
+    /// through the calls, and returns the produced values.
     ///
-    /// Doesn't [`peek()`](Self::peek()) at all, so it is faster than
-    /// [`next_while()`](Self::next_while()).
+    /// The item for which `f` returns [`None`] is not consumed.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(r#"a\"b" c"#.chars());
+    ///
+    /// // Consume up to an unescaped quote
+    /// let body = it.scan_while(false, |escaped, c| {
+    ///     let stop = *c == '"' && !*escaped;
+    ///     *escaped = *c == '\\' && !*escaped;
+    ///
+    ///     (!stop).then_some(*c)
+    /// });
+    ///
+    /// assert_eq!(body.into_iter().collect::<String>(), r#"a\"b"#);
+    /// assert_eq!(it.next(), Some('"'));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn scan_while<S, U, F>(&mut self, mut state: S, mut f: F) -> Vec<U>
+    where
+        F: FnMut(&mut S, &I::Item) -> Option<U>,
+    {
+        let mut result = vec![];
+
+        while let Some(x) = self.peek_ref().and_then(|x| f(&mut state, x)) {
+            result.push(x);
+            self.next();
+        }
+
+        result
+    }
+
+    /// Like [`next_while()`](Self::next_while()), except consumes the first
+    /// element that doesn't suffice (without returning it).
+    ///
+    /// Doesn't [`peek()`](Self::peek()) at all, so it is faster than
+    /// [`next_while()`](Self::next_while()).
     ///
     /// ```rust
     /// # use peeking_iter::PeekingIter;
@@ -159,6 +778,7 @@ impl<I: Iterator + Clone> PeekingIter<I> {
     /// assert_eq!(it.next(), Some(3));
     /// ```
     /// Note the `Some(3)`, instead of `Some(2)`.
+    #[cfg(feature = "alloc")]
     pub fn next_while1<F: Fn(&I::Item) -> bool>(&mut self, pred: F) -> Vec<I::Item> {
         let mut result = vec![];
 
@@ -178,6 +798,508 @@ impl<I: Iterator + Clone> PeekingIter<I> {
         result
     }
 
+    /// Returns the next run of consecutive items for which `key` returns
+    /// equal values.
+    ///
+    /// The returned `Vec` is empty only if the iterator is exhausted.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new([1, 3, 2, 4, 5].into_iter());
+    ///
+    /// assert_eq!(it.next_run_by_key(|x| x % 2), vec![1, 3]);
+    /// assert_eq!(it.next_run_by_key(|x| x % 2), vec![2, 4]);
+    /// assert_eq!(it.next(), Some(5));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn next_run_by_key<K: PartialEq, F: Fn(&I::Item) -> K>(&mut self, key: F) -> Vec<I::Item> {
+        let Some(first) = self.next() else {
+            return vec![];
+        };
+        let k = key(&first);
+
+        let mut result = vec![first];
+        result.extend(self.next_while(|x| key(x) == k));
+
+        result
+    }
+
+    /// Returns an iterator over runs of consecutive items for which `key`
+    /// returns equal values.
+    ///
+    /// See [`next_run_by_key()`](Self::next_run_by_key()).
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new("aabccc".chars());
+    ///
+    /// assert_eq!(
+    ///     it.runs_by_key(|c| *c).collect::<Vec<_>>(),
+    ///     vec![vec!['a', 'a'], vec!['b'], vec!['c', 'c', 'c']],
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn runs_by_key<K: PartialEq, F: Fn(&I::Item) -> K>(self, key: F) -> RunsByKey<I, F> {
+        RunsByKey::new(self, key)
+    }
+
+    /// Returns an iterator over segments separated by items matching the
+    /// predicate. The separators are dropped.
+    ///
+    /// Like [`str::split()`], a trailing separator produces an empty last
+    /// segment.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new([1, 0, 2, 3, 0].into_iter());
+    ///
+    /// assert_eq!(
+    ///     it.split_when(|x| *x == 0).collect::<Vec<_>>(),
+    ///     vec![vec![1], vec![2, 3], vec![]],
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn split_when<F: Fn(&I::Item) -> bool>(self, pred: F) -> SplitWhen<I, F> {
+        SplitWhen::new(self, pred, false)
+    }
+
+    /// Like [`split_when()`](Self::split_when()), except the separators are
+    /// kept at the end of each segment.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new([1, 0, 2, 3, 0].into_iter());
+    ///
+    /// assert_eq!(
+    ///     it.split_when_inclusive(|x| *x == 0).collect::<Vec<_>>(),
+    ///     vec![vec![1, 0], vec![2, 3, 0]],
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn split_when_inclusive<F: Fn(&I::Item) -> bool>(self, pred: F) -> SplitWhen<I, F> {
+        SplitWhen::new(self, pred, true)
+    }
+
+    /// Returns an iterator over overlapping windows of `size` items.
+    ///
+    /// Each window takes one item with [`next()`](Self::next()) and peeks
+    /// the rest, so the input is never collected as a whole.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new(0..4);
+    ///
+    /// assert_eq!(
+    ///     it.windows(3).collect::<Vec<_>>(),
+    ///     vec![vec![0, 1, 2], vec![1, 2, 3]],
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn windows(self, size: usize) -> Windows<I> {
+        Windows::new(self, size)
+    }
+
+    /// Like [`windows()`](Self::windows()), but with the window size known
+    /// at compile time, yielding arrays.
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..4).array_windows();
+    ///
+    /// assert_eq!(it.next(), Some([0, 1]));
+    /// assert_eq!(it.next(), Some([1, 2]));
+    /// assert_eq!(it.next(), Some([2, 3]));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    pub fn array_windows<const N: usize>(self) -> ArrayWindows<I, N> {
+        ArrayWindows::new(self)
+    }
+
+    /// Returns an iterator yielding each item along with its successor
+    /// (using a single peek per item).
+    ///
+    /// See [`Pairs`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new(0..3);
+    ///
+    /// assert_eq!(
+    ///     it.pairs().collect::<Vec<_>>(),
+    ///     vec![(0, Some(1)), (1, Some(2)), (2, None)],
+    /// );
+    /// ```
+    pub fn pairs(self) -> Pairs<I> {
+        Pairs::new(self)
+    }
+
+    /// Returns an iterator yielding each item along with its two
+    /// successors.
+    ///
+    /// See [`Triples`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..3).triples();
+    ///
+    /// assert_eq!(it.next(), Some((0, Some(1), Some(2))));
+    /// assert_eq!(it.next(), Some((1, Some(2), None)));
+    /// ```
+    pub fn triples(self) -> Triples<I> {
+        Triples::new(self)
+    }
+
+    /// Returns an iterator that repeatedly calls `f` with mutable access to
+    /// `self`, yielding its results until it returns [`None`].
+    ///
+    /// The closure can peek and consume freely, which makes it a reusable
+    /// tokenizer driver.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new("ab  c".chars());
+    /// let words = it.batches(|it| {
+    ///     it.next_while(|c| c.is_whitespace());
+    ///
+    ///     let word: String = it.next_while(|c| !c.is_whitespace()).into_iter().collect();
+    ///
+    ///     (!word.is_empty()).then_some(word)
+    /// });
+    ///
+    /// assert_eq!(words.collect::<Vec<_>>(), vec!["ab", "c"]);
+    /// ```
+    pub fn batches<B, F: FnMut(&mut Self) -> Option<B>>(self, f: F) -> Batches<I, F> {
+        Batches::new(self, f)
+    }
+
+    /// Zips `self` with another [`PeekingIter`], so that both are peeked
+    /// and advanced in lock-step.
+    ///
+    /// See [`ZipPeek`].
+    pub fn zip_peek<J: Iterator + Clone>(self, other: PeekingIter<J>) -> ZipPeek<I, J> {
+        ZipPeek::new(self, other)
+    }
+
+    /// Calls `f` on every peeked item along with its lookahead offset.
+    ///
+    /// See [`InspectPeek`].
+    pub fn inspect_peek<F: FnMut(&I::Item, usize)>(self, f: F) -> InspectPeek<I, F> {
+        InspectPeek::new(self, f)
+    }
+
+    /// Like [`Iterator::chain()`], but the result is still a
+    /// [`PeekingIter`], with the peek cursor crossing the boundary between
+    /// the two iterators.
+    ///
+    /// The peeking iterator is preserved.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..2);
+    ///
+    /// assert_eq!(it.peek(), Some(0));
+    ///
+    /// let mut it = it.chain_peeking(2..4);
+    ///
+    /// assert_eq!(it.peek(), Some(1));
+    /// assert_eq!(it.peek(), Some(2));
+    /// assert_eq!(it.next(), Some(0));
+    /// ```
+    pub fn chain_peeking<J>(self, other: J) -> PeekingIter<Chain<I, J>>
+    where
+        J: Iterator<Item = I::Item> + Clone,
+    {
+        let mut it = PeekingIter::new(self.iter.chain(other.clone()));
+        it.peeking = self.peeking.map(|peeking| peeking.chain(other));
+        it.peek_offset = self.peek_offset;
+        it.consumed = self.consumed;
+
+        it
+    }
+
+    /// Like [`Iterator::map()`], but the result is still a [`PeekingIter`].
+    ///
+    /// The peeking iterator is preserved.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=2);
+    ///
+    /// assert_eq!(it.peek(), Some(0));
+    ///
+    /// let mut it = it.map_peeking(|x| x * 10);
+    ///
+    /// assert_eq!(it.peek(), Some(10));
+    /// assert_eq!(it.next(), Some(0));
+    /// ```
+    pub fn map_peeking<B, F>(self, f: F) -> PeekingIter<Map<I, F>>
+    where
+        F: FnMut(I::Item) -> B + Clone,
+    {
+        let mut it = PeekingIter::new(self.iter.map(f.clone()));
+        it.peeking = self.peeking.map(|peeking| peeking.map(f));
+        it.peek_offset = self.peek_offset;
+        it.consumed = self.consumed;
+
+        it
+    }
+
+    /// Like [`Iterator::filter()`], but the result is still a
+    /// [`PeekingIter`].
+    ///
+    /// The peeking iterator is preserved.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=4).filter_peeking(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(it.peek(), Some(0));
+    /// assert_eq!(it.peek(), Some(2));
+    /// assert_eq!(it.next(), Some(0));
+    ///
+    /// // Items consumed before filtering are still counted
+    /// let mut it = PeekingIter::new(0..=4);
+    /// it.nth(1);
+    ///
+    /// let mut it = it.filter_peeking(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(it.items_consumed(), 2);
+    /// assert_eq!(it.next(), Some(2));
+    /// assert_eq!(it.items_consumed(), 3);
+    /// ```
+    pub fn filter_peeking<P>(self, pred: P) -> PeekingIter<Filter<I, P>>
+    where
+        P: FnMut(&I::Item) -> bool + Clone,
+    {
+        let mut it = PeekingIter::new(self.clone_base().filter(pred.clone()));
+        it.peek_offset = self
+            .iter
+            .take(self.peek_offset)
+            .filter(pred.clone())
+            .count();
+        it.peeking = self.peeking.map(|peeking| peeking.filter(pred));
+        it.consumed = self.consumed;
+
+        it
+    }
+
+    /// Consumes and discards items until one matches the predicate (without
+    /// consuming it).
+    ///
+    /// Returns `true` if such an item was found. Otherwise, the iterator is
+    /// exhausted.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new("a b ; c".split(' '));
+    ///
+    /// assert!(it.skip_until(|tok| *tok == ";"));
+    /// assert_eq!(it.next(), Some(";"));
+    /// assert!(!it.skip_until(|tok| *tok == ";"));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    pub fn skip_until<F: FnMut(&I::Item) -> bool>(&mut self, mut pred: F) -> bool {
+        self.rewind_peeking();
+
+        let mut n = 0;
+
+        loop {
+            match self.peek() {
+                None => {
+                    self.advance_to_peeked();
+
+                    return false;
+                }
+                Some(x) if pred(&x) => break,
+                Some(_) => n += 1,
+            }
+        }
+
+        self.rewind_peeking();
+
+        if n > 0 {
+            self.nth(n - 1);
+        }
+
+        true
+    }
+
+    /// Like [`skip_until()`](Self::skip_until()), except also consumes the
+    /// matching item.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new("a b ; c".split(' '));
+    ///
+    /// assert!(it.skip_through(|tok| *tok == ";"));
+    /// assert_eq!(it.next(), Some("c"));
+    /// ```
+    pub fn skip_through<F: FnMut(&I::Item) -> bool>(&mut self, pred: F) -> bool {
+        let found = self.skip_until(pred);
+
+        if found {
+            self.next();
+        }
+
+        found
+    }
+
+    /// Drops consecutive items for which `eq` returns `true`, keeping the
+    /// first one of each run.
+    ///
+    /// The result is still a [`PeekingIter`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new("aAbBBa".chars()).dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    ///
+    /// assert_eq!(it.peek(), Some('a'));
+    /// assert_eq!(it.peek(), Some('b'));
+    /// assert_eq!(it.collect::<String>(), "aba");
+    /// ```
+    pub fn dedup_by<F>(mut self, eq: F) -> PeekingIter<DedupBy<I, F>>
+    where
+        F: FnMut(&I::Item, &I::Item) -> bool + Clone,
+    {
+        self.rewind_peeking();
+
+        PeekingIter::new(DedupBy::new(self, eq))
+    }
+
+    /// Drops consecutive equal items.
+    ///
+    /// See [`dedup_by()`](Self::dedup_by()).
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let it = PeekingIter::new([1, 1, 2, 3, 3, 1].into_iter()).dedup();
+    ///
+    /// assert_eq!(it.collect::<Vec<_>>(), vec![1, 2, 3, 1]);
+    /// ```
+    pub fn dedup(self) -> PeekingIter<Dedup<I>>
+    where
+        I::Item: PartialEq,
+    {
+        self.dedup_by(PartialEq::eq)
+    }
+
+    /// Returns the number of items consumed so far (i.e. the position of
+    /// the base iterator).
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=5);
+    ///
+    /// it.next();
+    /// it.peek();
+    ///
+    /// assert_eq!(it.items_consumed(), 1);
+    ///
+    /// it.advance_to_peeked();
+    /// it.nth(1);
+    ///
+    /// assert_eq!(it.items_consumed(), 4);
+    /// ```
+    pub fn items_consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Returns the instrumentation counters.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=3);
+    ///
+    /// it.peek();
+    /// it.rewind_peeking();
+    /// it.peek();
+    /// it.peek();
+    /// it.advance_to_peeked();
+    ///
+    /// let stats = it.stats();
+    ///
+    /// assert_eq!(stats.clones, 2);
+    /// assert_eq!(stats.peeks, 3);
+    /// assert_eq!(stats.rewinds, 1);
+    /// assert_eq!(stats.commits, 1);
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> PeekStats {
+        self.stats.get()
+    }
+
+    #[cfg(feature = "stats")]
+    fn record<F: FnOnce(&mut PeekStats)>(&self, f: F) {
+        let mut stats = self.stats.get();
+
+        f(&mut stats);
+        self.stats.set(stats);
+    }
+
+    /// Clones the base iterator.
+    fn clone_base(&self) -> I {
+        #[cfg(feature = "stats")]
+        self.record(|stats| stats.clones += 1);
+
+        self.iter.clone()
+    }
+
+    /// Clones the base iterator to look ahead from it, recording the scan
+    /// for the repeat peek protection.
+    fn scan_base(&self) -> I {
+        #[cfg(feature = "peek-protection")]
+        {
+            let mut protection = self.protection.get();
+
+            protection.on_scan();
+            self.protection.set(protection);
+        }
+
+        self.clone_base()
+    }
+
+    /// Records that the base iterator has advanced by `n` items.
+    fn advanced(&mut self, n: usize) {
+        self.consumed += n;
+
+        #[cfg(feature = "peek-protection")]
+        if n > 0 {
+            self.protection.get_mut().on_advance();
+        }
+    }
+
+    /// Lazily merges `self` with another sorted [`PeekingIter`], using `cmp`
+    /// to order the items.
+    ///
+    /// The result is still a [`PeekingIter`]. See [`MergeSorted`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let a = PeekingIter::new([1, 3, 5].into_iter());
+    /// let b = PeekingIter::new([2, 3, 4].into_iter());
+    /// let mut it = a.merge_sorted(b, Ord::cmp);
+    ///
+    /// assert_eq!(it.peek(), Some(1));
+    /// assert_eq!(it.collect::<Vec<_>>(), vec![1, 2, 3, 3, 4, 5]);
+    /// ```
+    pub fn merge_sorted<J, F>(
+        self,
+        other: PeekingIter<J>,
+        cmp: F,
+    ) -> PeekingIter<MergeSorted<I, J, F>>
+    where
+        J: Iterator<Item = I::Item> + Clone,
+        F: FnMut(&I::Item, &I::Item) -> Ordering + Clone,
+    {
+        PeekingIter::new(MergeSorted::new(self, other, cmp))
+    }
+
     /// Consumes `self` and returns the inner (base) iterator.
     ///
     /// ```rust
@@ -191,10 +1313,183 @@ impl<I: Iterator + Clone> PeekingIter<I> {
     }
 }
 
+impl<I: Iterator + Clone> Clone for PeekingIter<I> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            peeking: self.peeking.clone(),
+            peek_offset: self.peek_offset,
+            peeking_back: self.peeking_back.clone(),
+            // Items aren't required to implement `Clone`
+            peeked: None,
+            consumed: self.consumed,
+            #[cfg(feature = "stats")]
+            stats: self.stats.clone(),
+            #[cfg(feature = "peek-protection")]
+            protection: self.protection.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for PeekingIter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PeekingIter")
+            .field("iter", &self.iter)
+            .field("peeking", &self.peeking)
+            .finish()
+    }
+}
+
+/// Compares the base iterators, ignoring the peeking state.
+///
+/// ```rust
+/// # use peeking_iter::PeekingIter;
+/// let mut a = PeekingIter::new(0..=2);
+/// let mut b = PeekingIter::new(1..=2);
+///
+/// a.next();
+/// b.peek();
+///
+/// assert_eq!(a, b);
+/// ```
+impl<I: Iterator + PartialEq> PartialEq for PeekingIter<I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter == other.iter
+    }
+}
+
+impl<I: Iterator + Eq> Eq for PeekingIter<I> {}
+
+/// Reads bytes from the base iterator, consuming them.
+///
+/// ```rust
+/// # use peeking_iter::PeekingIter;
+/// use std::io::Read;
+///
+/// let mut it = PeekingIter::new(b"hello".iter().copied());
+///
+/// assert_eq!(it.peek(), Some(b'h'));
+///
+/// let mut buf = [0; 4];
+///
+/// assert_eq!(it.read(&mut buf).unwrap(), 4);
+/// assert_eq!(&buf, b"hell");
+/// assert_eq!(it.next(), Some(b'o'));
+/// ```
+#[cfg(feature = "std")]
+impl<I: Iterator<Item = u8> + Clone> std::io::Read for PeekingIter<I> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.rewind_peeking();
+
+        let mut n = 0;
+
+        for (slot, byte) in buf.iter_mut().zip(&mut self.iter) {
+            *slot = byte;
+            n += 1;
+        }
+
+        self.advanced(n);
+
+        Ok(n)
+    }
+}
+
+impl<I: DoubleEndedIterator + Clone> PeekingIter<I> {
+    /// Peeks the last item in the inner iterator.
+    ///
+    /// Subsequent calls return preceding items. The back peeking iterator
+    /// is reset along with the (front) peeking one.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=2);
+    ///
+    /// assert_eq!(it.peek_back(), Some(2));
+    /// assert_eq!(it.peek_back(), Some(1));
+    /// assert_eq!(it.next(), Some(0));
+    /// assert_eq!(it.peek_back(), Some(2));
+    /// ```
+    pub fn peek_back(&mut self) -> Option<I::Item> {
+        if self.peeking_back.is_none() {
+            self.peeking_back = Some(self.clone_base());
+        }
+
+        #[cfg(feature = "stats")]
+        self.record(|stats| stats.peeks += 1);
+
+        self.peeking_back
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+    }
+
+    /// Peek the `n`th value from the back of the iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=2);
+    ///
+    /// assert_eq!(it.peek_nth_back(2), Some(0));
+    /// assert_eq!(it.peek_nth_back(0), None);
+    /// assert_eq!(it.next(), Some(0));
+    /// ```
+    pub fn peek_nth_back(&mut self, n: usize) -> Option<I::Item> {
+        if self.peeking_back.is_none() {
+            self.peeking_back = Some(self.clone_base());
+        }
+
+        #[cfg(feature = "stats")]
+        self.record(|stats| stats.peeks += 1);
+
+        self.peeking_back.as_mut().and_then(|it| it.nth_back(n))
+    }
+}
+
 impl<I: Iterator + Clone> Iterator for PeekingIter<I> {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
         PeekingIter::next(self)
     }
+
+    // NOTE: The methods below are forwarded to the base iterator, so that
+    // internal iteration (`sum()`, `for_each()`, etc.) stays as fast as the
+    // original. `try_fold()` can't be overridden on stable Rust.
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.iter.last()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (lo, hi) = self.iter.size_hint();
+
+        // Without an exact size, there's no way to tell how many items a
+        // failed `nth()` has consumed
+        if hi != Some(lo) {
+            for _ in 0..n {
+                PeekingIter::next(self)?;
+            }
+
+            return PeekingIter::next(self);
+        }
+
+        self.rewind_peeking();
+
+        let nth = self.iter.nth(n);
+
+        self.advanced(lo.min(n.saturating_add(1)));
+
+        nth
+    }
+
+    fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, f: F) -> B {
+        self.iter.fold(init, f)
+    }
 }
@@ -0,0 +1,108 @@
+/// An iterator whose items may borrow from the iterator itself.
+pub trait LendingIterator {
+    /// The type of the items, borrowing from the iterator for `'a`.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Advances the iterator and returns the next item.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+/// Lending iterator adapter that enables infinitely-deep peeking.
+///
+/// The [`LendingIterator`] counterpart of
+/// [`PeekingIter`](crate::PeekingIter). Peeked items borrow from the
+/// adapter, so each one has to be dropped before peeking further.
+///
+/// The inner iterator is required to implement [`Clone`].
+///
+/// ```rust
+/// # use peeking_iter::{LendingIterator, PeekingLendingIter};
+/// // Yields words from a reused internal buffer
+/// #[derive(Clone)]
+/// struct Words<'s> {
+///     src: std::str::SplitWhitespace<'s>,
+///     buf: String,
+/// }
+///
+/// impl LendingIterator for Words<'_> {
+///     type Item<'a> = &'a str where Self: 'a;
+///
+///     fn next(&mut self) -> Option<&str> {
+///         self.buf.clear();
+///         self.buf.push_str(self.src.next()?);
+///
+///         Some(&self.buf)
+///     }
+/// }
+///
+/// let mut it = PeekingLendingIter::new(Words {
+///     src: "a b c".split_whitespace(),
+///     buf: String::new(),
+/// });
+///
+/// assert_eq!(it.next(), Some("a"));
+/// assert_eq!(it.peek(), Some("b"));
+/// assert_eq!(it.peek(), Some("c"));
+/// assert_eq!(it.next(), Some("b"));
+/// ```
+pub struct PeekingLendingIter<I> {
+    iter: I,
+    peeking: Option<I>,
+}
+
+impl<I: LendingIterator + Clone> PeekingLendingIter<I> {
+    /// Wraps the given iterator.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            peeking: None,
+        }
+    }
+
+    /// Returns the next item in the inner iterator.
+    ///
+    /// Resets the peeking iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<I::Item<'_>> {
+        self.peeking = None;
+
+        self.iter.next()
+    }
+
+    /// Peeks the next item in the inner iterator.
+    ///
+    /// Subsequent calls return subsequent items.
+    pub fn peek(&mut self) -> Option<I::Item<'_>> {
+        self.peeking.get_or_insert_with(|| self.iter.clone()).next()
+    }
+
+    /// Advances the base iterator to the be aligned with the peeking one.
+    pub fn advance_to_peeked(&mut self) {
+        if let Some(peeking) = self.peeking.take() {
+            self.iter = peeking;
+        }
+    }
+
+    /// Rewind the peeking iterator to align with the base one.
+    pub fn rewind_peeking(&mut self) {
+        self.peeking = None;
+    }
+
+    /// Consumes `self` and returns the inner (base) iterator.
+    pub fn into_inner(value: Self) -> I {
+        value.iter
+    }
+}
+
+impl<I: LendingIterator + Clone> LendingIterator for PeekingLendingIter<I> {
+    type Item<'a>
+        = I::Item<'a>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<I::Item<'_>> {
+        PeekingLendingIter::next(self)
+    }
+}
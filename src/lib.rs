@@ -1,5 +1,90 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "async-read")]
+mod async_read;
+mod batches;
+#[cfg(feature = "alloc")]
+mod buffered;
+#[cfg(feature = "alloc")]
+mod by_ref;
+mod bytes;
+mod class;
+#[cfg(feature = "codespan-reporting")]
+mod codespan;
+mod cursor;
+mod dedup;
+mod fixed;
+mod inspect;
 mod iter;
-mod parser;
+mod lending;
+mod lines;
+#[cfg(feature = "alloc")]
+mod lookahead;
+mod merge;
+mod outcome;
+mod pairs;
+pub mod parser;
+mod peek_core;
+mod position;
+#[cfg(feature = "peek-protection")]
+mod protection;
+#[cfg(feature = "std")]
+mod read;
+#[cfg(feature = "regex")]
+mod regex;
+#[cfg(feature = "alloc")]
+mod runs;
+#[cfg(feature = "std")]
+mod shared;
+mod snippet;
+#[cfg(feature = "alloc")]
+mod split;
+#[cfg(feature = "stats")]
+mod stats;
+mod strategy;
+#[cfg(feature = "async")]
+mod stream;
+mod token;
+mod windows;
+mod zip;
 
+pub use batches::Batches;
+#[cfg(feature = "alloc")]
+pub use buffered::{BufferedPeekingIter, DynPeekingIter};
+#[cfg(feature = "alloc")]
+pub use by_ref::{PeekingByRef, PeekingByRefExt};
+pub use cursor::Cursor;
+pub use dedup::{Dedup, DedupBy};
+pub use fixed::PeekingIterN;
+pub use inspect::InspectPeek;
 pub use iter::PeekingIter;
+pub use lending::{LendingIterator, PeekingLendingIter};
+#[cfg(feature = "alloc")]
+pub use lookahead::Lookahead;
+pub use merge::MergeSorted;
+pub use outcome::Outcome;
+pub use pairs::{Pairs, Triples};
 pub use parser::Parser;
+pub use position::{Position, Span};
+#[cfg(feature = "peek-protection")]
+pub use protection::RepeatPeekAction;
+#[cfg(feature = "alloc")]
+pub use runs::RunsByKey;
+#[cfg(feature = "std")]
+pub use shared::SharedPeekingIter;
+#[cfg(feature = "alloc")]
+pub use split::SplitWhen;
+#[cfg(feature = "stats")]
+pub use stats::PeekStats;
+pub use strategy::PeekStrategy;
+#[cfg(feature = "async")]
+pub use stream::PeekingStream;
+pub use windows::ArrayWindows;
+#[cfg(feature = "alloc")]
+pub use windows::Windows;
+pub use zip::ZipPeek;
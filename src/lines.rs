@@ -0,0 +1,63 @@
+use crate::{parser::Terminator, Parser, Position};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::str::Chars;
+
+/// An iterator over the lines of a [`Parser`], paired with their starting
+/// positions.
+///
+/// Created by [`Parser::lines()`].
+#[cfg(feature = "alloc")]
+pub struct Lines<'p, I: Iterator<Item = char>> {
+    parser: &'p mut Parser<I>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'p, I: Iterator<Item = char> + Clone> Lines<'p, I> {
+    pub(crate) fn new(parser: &'p mut Parser<I>) -> Self {
+        Self { parser }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char> + Clone> Iterator for Lines<'_, I> {
+    type Item = (Position, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parser.at_end() {
+            return None;
+        }
+
+        let start = Parser::position(self.parser);
+
+        Some((start, self.parser.take_line(Terminator::Skip)))
+    }
+}
+
+/// An iterator over the lines of a [`StrParser`](crate::parser::StrParser)
+/// as slices of the input, paired with their starting positions.
+///
+/// Created by [`Parser::lines_str()`].
+pub struct LinesStr<'p, 'a> {
+    parser: &'p mut Parser<Chars<'a>>,
+}
+
+impl<'p, 'a> LinesStr<'p, 'a> {
+    pub(crate) fn new(parser: &'p mut Parser<Chars<'a>>) -> Self {
+        Self { parser }
+    }
+}
+
+impl<'a> Iterator for LinesStr<'_, 'a> {
+    type Item = (Position, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parser.as_str().is_empty() {
+            return None;
+        }
+
+        let start = Parser::position(self.parser);
+
+        Some((start, self.parser.take_line_str(Terminator::Skip)))
+    }
+}
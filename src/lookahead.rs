@@ -0,0 +1,66 @@
+use alloc::collections::VecDeque;
+
+/// Iterator adapter that keeps a window of up to `size` upcoming items in a
+/// ring buffer, exposed as a slice.
+///
+/// Unlike [`PeekingIter`](crate::PeekingIter), the inner iterator doesn't
+/// need to implement [`Clone`].
+///
+/// ```rust
+/// # use peeking_iter::Lookahead;
+/// let mut it = Lookahead::new("--flag".chars(), 2);
+///
+/// assert!(matches!(it.lookahead(), ['-', '-', ..]));
+/// assert_eq!(it.next(), Some('-'));
+/// assert_eq!(it.lookahead(), ['-', 'f']);
+/// ```
+pub struct Lookahead<I: Iterator> {
+    iter: I,
+    buf: VecDeque<I::Item>,
+    size: usize,
+}
+
+impl<I: Iterator> Lookahead<I> {
+    /// Wraps the given iterator, keeping a window of `size` items.
+    pub fn new(iter: I, size: usize) -> Self {
+        Self {
+            iter,
+            buf: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+
+    /// Returns the next item, taking it from the window if it's there.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<I::Item> {
+        self.buf.pop_front().or_else(|| self.iter.next())
+    }
+
+    /// Returns the upcoming items, without consuming them.
+    ///
+    /// The slice is shorter than the window size only if the inner iterator
+    /// ran out.
+    pub fn lookahead(&mut self) -> &[I::Item] {
+        while self.buf.len() < self.size {
+            match self.iter.next() {
+                Some(x) => self.buf.push_back(x),
+                None => break,
+            }
+        }
+
+        self.buf.make_contiguous()
+    }
+
+    /// Returns the window size.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl<I: Iterator> Iterator for Lookahead<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        Lookahead::next(self)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::PeekingIter;
+use core::cmp::Ordering;
+
+/// Iterator lazily merging two sorted [`PeekingIter`]s.
+///
+/// Created by [`PeekingIter::merge_sorted()`]. On ties, items from the
+/// first iterator come first.
+#[derive(Clone)]
+pub struct MergeSorted<I: Iterator, J: Iterator, F> {
+    a: PeekingIter<I>,
+    b: PeekingIter<J>,
+    cmp: F,
+}
+
+impl<I: Iterator + Clone, J: Iterator + Clone, F> MergeSorted<I, J, F> {
+    pub(crate) fn new(a: PeekingIter<I>, b: PeekingIter<J>, cmp: F) -> Self {
+        Self { a, b, cmp }
+    }
+
+    /// Splits the adapter back into the two [`PeekingIter`]s, positioned
+    /// right after their last merged items.
+    ///
+    /// ```rust
+    /// # use peeking_iter::{MergeSorted, PeekingIter};
+    /// let mut it = PeekingIter::new([1, 4].into_iter())
+    ///     .merge_sorted(PeekingIter::new([2, 3].into_iter()), Ord::cmp);
+    ///
+    /// assert_eq!(it.next(), Some(1));
+    /// assert_eq!(it.next(), Some(2));
+    ///
+    /// let (mut a, mut b) = MergeSorted::into_parts(PeekingIter::into_inner(it));
+    ///
+    /// assert_eq!(a.peek(), Some(4));
+    /// assert_eq!(b.peek(), Some(3));
+    /// ```
+    pub fn into_parts(value: Self) -> (PeekingIter<I>, PeekingIter<J>) {
+        (value.a, value.b)
+    }
+}
+
+impl<T, I, J, F> Iterator for MergeSorted<I, J, F>
+where
+    I: Iterator<Item = T> + Clone,
+    J: Iterator<Item = T> + Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let take_a = match (self.a.peek_ref(), self.b.peek_ref()) {
+            (Some(x), Some(y)) => (self.cmp)(x, y) != Ordering::Greater,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+
+        if take_a {
+            self.a.next()
+        } else {
+            self.b.next()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+
+        (a_lo.saturating_add(b_lo), hi)
+    }
+}
@@ -0,0 +1,21 @@
+/// A result-like value that reports whether an operation succeeded.
+///
+/// Implemented for [`Option`] and [`Result`], so that
+/// [`PeekingIter::speculate()`](crate::PeekingIter::speculate()) can decide
+/// whether to commit or roll back.
+pub trait Outcome {
+    /// Returns `true` if the operation succeeded.
+    fn is_success(&self) -> bool;
+}
+
+impl<T> Outcome for Option<T> {
+    fn is_success(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl<T, E> Outcome for Result<T, E> {
+    fn is_success(&self) -> bool {
+        self.is_ok()
+    }
+}
@@ -0,0 +1,79 @@
+use crate::PeekingIter;
+
+/// Iterator yielding each item along with its successor.
+///
+/// Created by [`PeekingIter::pairs()`]. The successor is peeked, so it's
+/// possible to keep peeking further with [`peek()`](Self::peek()).
+pub struct Pairs<I: Iterator> {
+    iter: PeekingIter<I>,
+}
+
+impl<I: Iterator + Clone> Pairs<I> {
+    pub(crate) fn new(iter: PeekingIter<I>) -> Self {
+        Self { iter }
+    }
+
+    /// Peeks the item after the last yielded successor.
+    ///
+    /// Subsequent calls return subsequent items.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=3).pairs();
+    ///
+    /// assert_eq!(it.next(), Some((0, Some(1))));
+    /// assert_eq!(it.peek(), Some(2));
+    /// assert_eq!(it.peek(), Some(3));
+    /// assert_eq!(it.next(), Some((1, Some(2))));
+    /// ```
+    pub fn peek(&mut self) -> Option<I::Item> {
+        self.iter.peek()
+    }
+
+    /// Consumes `self` and returns the wrapped [`PeekingIter`].
+    pub fn into_inner(value: Self) -> PeekingIter<I> {
+        value.iter
+    }
+}
+
+impl<I: Iterator + Clone> Iterator for Pairs<I> {
+    type Item = (I::Item, Option<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.iter.next()?, self.iter.peek()))
+    }
+}
+
+/// Iterator yielding each item along with its two successors.
+///
+/// Created by [`PeekingIter::triples()`]. The successors are peeked, so
+/// it's possible to keep peeking further with [`peek()`](Self::peek()).
+pub struct Triples<I: Iterator> {
+    iter: PeekingIter<I>,
+}
+
+impl<I: Iterator + Clone> Triples<I> {
+    pub(crate) fn new(iter: PeekingIter<I>) -> Self {
+        Self { iter }
+    }
+
+    /// Peeks the item after the last yielded successors.
+    ///
+    /// Subsequent calls return subsequent items.
+    pub fn peek(&mut self) -> Option<I::Item> {
+        self.iter.peek()
+    }
+
+    /// Consumes `self` and returns the wrapped [`PeekingIter`].
+    pub fn into_inner(value: Self) -> PeekingIter<I> {
+        value.iter
+    }
+}
+
+impl<I: Iterator + Clone> Iterator for Triples<I> {
+    type Item = (I::Item, Option<I::Item>, Option<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.iter.next()?, self.iter.peek(), self.iter.peek()))
+    }
+}
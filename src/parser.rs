@@ -1,3 +1,448 @@
+//! String parsing with [`Parser`] and its helper types.
+
+#[cfg(feature = "async-read")]
+pub use crate::async_read::AsyncParser;
+pub use crate::bytes::{BytesParseError, BytesParser, ExpectedBytes};
+pub use crate::class::CharClass;
+#[cfg(feature = "alloc")]
+pub use crate::lines::Lines;
+pub use crate::lines::LinesStr;
+pub use crate::position::{Position, Span};
+#[cfg(feature = "std")]
+pub use crate::read::ReadChars;
+pub use crate::snippet::Snippet;
+pub use crate::token::{ExpectedToken, TokenCheckpoint, TokenParseError, TokenParser};
+#[cfg(feature = "derive")]
+pub use peeking_iter_derive::FromParser;
+
+use crate::{
+    peek_core::{PeekCore, Track},
+    Outcome,
+};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::{fmt, str::Chars};
+
+/// Line endings recognized by a [`Parser`].
+///
+/// Set with [`Parser::line_endings()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LineEndings {
+    /// `\n` only.
+    Lf,
+    /// `\r\n` only.
+    CrLf,
+    /// `\r` only.
+    Cr,
+    /// Any of `\n`, `\r\n` and `\r`.
+    #[default]
+    Any,
+}
+
+impl LineEndings {
+    /// Returns whether `\n`, `\r\n` and `\r` are recognized, respectively.
+    pub(crate) fn recognized(self) -> (bool, bool, bool) {
+        match self {
+            Self::Lf => (true, false, false),
+            Self::CrLf => (false, true, false),
+            Self::Cr => (false, false, true),
+            Self::Any => (true, true, true),
+        }
+    }
+}
+
+/// A character predicate, implemented for `char` and `Fn(char) -> bool`.
+pub trait CharPattern {
+    /// Returns `true` if `c` matches the pattern.
+    fn matches(&self, c: char) -> bool;
+}
+
+impl CharPattern for char {
+    fn matches(&self, c: char) -> bool {
+        *self == c
+    }
+}
+
+impl<F: Fn(char) -> bool> CharPattern for F {
+    fn matches(&self, c: char) -> bool {
+        self(c)
+    }
+}
+
+/// Comment syntax skipped by [`Parser::skip_trivia()`].
+///
+/// ```rust
+/// # use peeking_iter::parser::Comments;
+/// let c_like = Comments {
+///     line: &["//"],
+///     block: &[("/*", "*/")],
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Comments {
+    /// Prefixes of comments that span until the end of the line.
+    pub line: &'static [&'static str],
+    /// Delimiters of comments that span until the closing delimiter.
+    pub block: &'static [(&'static str, &'static str)],
+}
+
+/// A saved state of a [`Parser`].
+///
+/// Created by [`Parser::checkpoint()`] and restored by
+/// [`Parser::rollback()`].
+#[derive(Clone, Debug)]
+pub struct Checkpoint<I> {
+    iter: I,
+    location: Location<I>,
+}
+
+impl<I> Checkpoint<I> {
+    /// Returns the position the checkpoint was created at.
+    pub fn position(&self) -> Position {
+        self.location.pos
+    }
+}
+
+/// Where a [`Parser`] is in its input.
+#[derive(Clone, Debug)]
+pub(crate) struct Location<I> {
+    pos: Position,
+    char_offset: usize,
+    after_cr: bool,
+    /// The iterator at the start of the current line.
+    line_start: I,
+}
+
+impl<I: Iterator<Item = char> + Clone> Track<I, Location<I>> for LineEndings {
+    fn bump(&mut self, location: &mut Location<I>, &c: &char, rest: &I) {
+        let line = location.pos.line;
+
+        location.char_offset += 1;
+
+        step(&mut location.pos, &mut location.after_cr, *self, c, || {
+            rest.clone().next()
+        });
+
+        if location.pos.line != line {
+            location.line_start = rest.clone();
+        }
+    }
+}
+
+/// What to do with the terminator in [`Parser::take_until()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Terminator {
+    /// Leave the terminator in the input.
+    #[default]
+    Keep,
+    /// Consume the terminator and include it in the result.
+    Include,
+    /// Consume the terminator, but don't include it in the result.
+    Skip,
+}
+
+/// A change of indentation, returned by [`Parser::update_indent()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndentChange {
+    /// The line is indented deeper than the previous level.
+    Indent,
+    /// The line is at the same level.
+    Same,
+    /// The line closes the given number of levels.
+    Dedent(usize),
+}
+
+/// Options for [`Parser::parse_separated()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Separated {
+    /// Whether a separator may follow the last item.
+    pub trailing: bool,
+    /// The minimum number of items.
+    pub min: usize,
+}
+
+/// A primitive integer type, parsed by [`Parser::parse_integer()`].
+pub trait Integer: Copy {
+    /// Whether the type can be negative.
+    const SIGNED: bool;
+    /// The value `0`.
+    const ZERO: Self;
+
+    /// Appends `digit` in the given `radix`, subtracting it instead of
+    /// adding if `negative`.
+    ///
+    /// Returns `None` on overflow.
+    fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal: $($t:ty),*) => {$(
+        impl Integer for $t {
+            const SIGNED: bool = $signed;
+            const ZERO: Self = 0;
+
+            fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self> {
+                let shifted = self.checked_mul(radix.try_into().ok()?)?;
+                let digit = digit.try_into().ok()?;
+
+                if negative {
+                    shifted.checked_sub(digit)
+                } else {
+                    shifted.checked_add(digit)
+                }
+            }
+        }
+
+        impl FromParser for $t {
+            /// Parses a decimal integer with [`Parser::parse_integer()`].
+            fn parse<I: Iterator<Item = char> + Clone>(
+                parser: &mut Parser<I>,
+            ) -> Result<Self, ParseError> {
+                parser.parse_integer(10)
+            }
+        }
+    )*};
+}
+
+impl_integer!(true: i8, i16, i32, i64, i128, isize);
+impl_integer!(false: u8, u16, u32, u64, u128, usize);
+
+/// A number literal, parsed by [`Parser::parse_number()`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Number {
+    /// An integer literal.
+    Int(i64),
+    /// A literal with a fraction or an exponent.
+    Float(f64),
+}
+
+/// A type that can be parsed from a [`Parser`], with [`Parser::parse()`].
+///
+/// The parser counterpart of [`FromStr`](core::str::FromStr), preserving
+/// positions in errors.
+///
+/// With the `derive` feature, it can be derived for simple structs and enums
+/// made of literals and other `FromParser` types.
+///
+/// ```rust
+/// # use peeking_iter::parser::{FromParser, ParseError, Parser};
+/// #[derive(Debug, PartialEq)]
+/// struct Rgb(u8, u8, u8);
+///
+/// impl FromParser for Rgb {
+///     fn parse<I>(p: &mut Parser<I>) -> Result<Self, ParseError>
+///     where
+///         I: Iterator<Item = char> + Clone,
+///     {
+///         p.expect('#')?;
+///
+///         let mut channel = || {
+///             let hi = p.one_of("0123456789abcdef")?.to_digit(16).unwrap();
+///             let lo = p.one_of("0123456789abcdef")?.to_digit(16).unwrap();
+///
+///             Ok((hi * 16 + lo) as u8)
+///         };
+///
+///         Ok(Rgb(channel()?, channel()?, channel()?))
+///     }
+/// }
+///
+/// let mut it = Parser::new("#ff8000 #12".chars());
+/// assert_eq!(it.parse(), Ok(Rgb(255, 128, 0)));
+///
+/// it.next();
+///
+/// assert_eq!(it.parse::<Rgb>().unwrap_err().position.col, 11);
+/// assert_eq!(it.col(), 8);
+/// ```
+pub trait FromParser: Sized {
+    /// Parses a value at the current position of `parser`.
+    fn parse<I: Iterator<Item = char> + Clone>(parser: &mut Parser<I>) -> Result<Self, ParseError>;
+}
+
+#[cfg(feature = "alloc")]
+impl FromParser for f64 {
+    /// Parses a number with [`Parser::parse_float()`].
+    fn parse<I: Iterator<Item = char> + Clone>(parser: &mut Parser<I>) -> Result<Self, ParseError> {
+        parser.parse_float().map(|(x, _)| x)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromParser for Number {
+    /// Parses a number with [`Parser::parse_number()`].
+    fn parse<I: Iterator<Item = char> + Clone>(parser: &mut Parser<I>) -> Result<Self, ParseError> {
+        parser.parse_number().map(|(x, _)| x)
+    }
+}
+
+/// What a [`Parser`] expected to find.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    /// A specific character.
+    Char(char),
+    /// A specific string.
+    #[cfg(feature = "alloc")]
+    Str(String),
+    /// A character matching a predicate.
+    Matching,
+    /// A character in the given set.
+    OneOf(&'static str),
+    /// A character not in the given set.
+    NoneOf(&'static str),
+    /// A character in the given class.
+    Class(CharClass),
+    /// Something described by the given string.
+    Description(&'static str),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Char(c) => write!(f, "{c:?}"),
+            #[cfg(feature = "alloc")]
+            Self::Str(s) => write!(f, "{s:?}"),
+            Self::Matching => f.write_str("matching character"),
+            Self::OneOf(set) => write!(f, "one of {set:?}"),
+            Self::NoneOf(set) => write!(f, "none of {set:?}"),
+            Self::Class(class) => write!(f, "one of {class}"),
+            Self::Description(d) => f.write_str(d),
+        }
+    }
+}
+
+/// The category of a [`ParseError`].
+///
+/// ```rust
+/// # use peeking_iter::parser::{ErrorKind, Parser};
+/// let mut it = Parser::new(r#"x 300 "\q""#.chars());
+///
+/// assert_eq!(it.expect('y').unwrap_err().kind, ErrorKind::UnexpectedChar);
+/// it.next();
+/// it.next();
+///
+/// assert_eq!(it.parse_integer::<u8>(10).unwrap_err().kind, ErrorKind::Overflow);
+/// it.next_while(|c| c != '"');
+///
+/// assert_eq!(it.parse_string('"').unwrap_err().kind, ErrorKind::InvalidEscape);
+/// it.next_while(|_| true);
+///
+/// assert_eq!(it.expect('y').unwrap_err().kind, ErrorKind::UnexpectedEof);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A character didn't match what was expected.
+    UnexpectedChar,
+    /// The input ended where more was expected.
+    UnexpectedEof,
+    /// An escape sequence in a string literal is invalid.
+    InvalidEscape,
+    /// A number doesn't fit in its type.
+    Overflow,
+    /// An error created with [`Parser::custom_error()`], with its message
+    /// as the [`Expected::Description`].
+    Custom,
+}
+
+/// An error returned by [`Parser`]'s matching helpers, such as
+/// [`expect()`](Parser::expect()) and the `parse_*` methods.
+///
+/// ```rust
+/// # use peeking_iter::parser::{ErrorKind, Expected, ParseError, Parser, Position};
+/// let mut it = Parser::new("a\n;".chars());
+///
+/// it.next();
+/// it.next();
+///
+/// assert_eq!(
+///     it.expect(','),
+///     Err(ParseError {
+///         position: Position { line: 2, col: 0, offset: 2 },
+///         found: Some(';'),
+///         expected: Expected::Char(','),
+///         kind: ErrorKind::UnexpectedChar,
+///         source_name: None,
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Position of the offending character.
+    pub position: Position,
+    /// The offending character, or `None` at the end of input.
+    pub found: Option<char>,
+    /// What was expected instead.
+    pub expected: Expected,
+    /// The category of the error.
+    pub kind: ErrorKind,
+    /// Name of the parsed source, set by
+    /// [`Parser::with_source_name()`].
+    #[cfg(feature = "alloc")]
+    pub source_name: Option<Arc<str>>,
+}
+
+impl fmt::Display for ParseError {
+    /// Formats the error as `name:line:col: message`, omitting the name if
+    /// it isn't set.
+    ///
+    /// Columns are displayed starting at 1, as editors expect.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("(x".chars()).with_source_name("main.lisp");
+    ///
+    /// it.next();
+    ///
+    /// assert_eq!(
+    ///     it.expect(')').unwrap_err().to_string(),
+    ///     "main.lisp:1:2: expected ')', found 'x'",
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        if let Some(name) = &self.source_name {
+            write!(f, "{name}:")?;
+        }
+
+        write!(f, "{}:{}: ", self.position.line, self.position.col + 1)?;
+
+        if self.kind == ErrorKind::Custom {
+            return write!(f, "{}", self.expected);
+        }
+
+        write!(f, "expected {}, found ", self.expected)?;
+
+        match self.found {
+            Some(c) => write!(f, "{c:?}"),
+            None => f.write_str("end of input"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Creates an error without a source name, categorized by `found`.
+    pub(crate) fn new(position: Position, found: Option<char>, expected: Expected) -> Self {
+        let kind = match found {
+            Some(_) => ErrorKind::UnexpectedChar,
+            None => ErrorKind::UnexpectedEof,
+        };
+
+        Self {
+            position,
+            found,
+            expected,
+            kind,
+            #[cfg(feature = "alloc")]
+            source_name: None,
+        }
+    }
+}
+
 /// An iterator implementing most (or all) of
 /// [`PeekingIter`](crate::PeekingIter)'s API, but designed
 /// specifically for parsing string input.
@@ -8,46 +453,152 @@ pub struct Parser<I>
 where
     I: Iterator<Item = char>,
 {
-    iter: I,
-    peeking: Option<I>,
-    line: u16,
-    col: u16,
+    core: PeekCore<I, LineEndings, Location<I>>,
+    #[cfg(feature = "alloc")]
+    is_whitespace: Box<dyn CharPattern + Send + Sync>,
+    #[cfg(not(feature = "alloc"))]
+    is_whitespace: fn(char) -> bool,
+    comments: Comments,
+    #[cfg(feature = "alloc")]
+    source_name: Option<Arc<str>>,
+    /// Indentation of the enclosing blocks, excluding the top level.
+    #[cfg(feature = "alloc")]
+    indents: Vec<usize>,
+    #[cfg(feature = "alloc")]
+    errors: Vec<ParseError>,
 }
 
-// TODO?: Implement whitespace-skipping
 impl<I: Iterator<Item = char> + Clone> Parser<I> {
     /// Wraps the given iterator.
     pub fn new(iter: I) -> Self {
+        let location = Location {
+            pos: Position::default(),
+            char_offset: 0,
+            after_cr: false,
+            line_start: iter.clone(),
+        };
+
         Self {
-            iter,
-            peeking: None,
-            line: 1,
-            col: 0,
+            core: PeekCore::new(iter, LineEndings::default(), location),
+            #[cfg(feature = "alloc")]
+            is_whitespace: Box::new(char::is_whitespace),
+            #[cfg(not(feature = "alloc"))]
+            is_whitespace: char::is_whitespace,
+            comments: Comments::default(),
+            #[cfg(feature = "alloc")]
+            source_name: None,
+            #[cfg(feature = "alloc")]
+            indents: Vec::new(),
+            #[cfg(feature = "alloc")]
+            errors: Vec::new(),
         }
     }
 
-    /// Returns the next item in the inner iterator.
+    /// Sets the line endings used for line and column counting.
     ///
-    /// Resets the peeking iterator.
-    pub fn next(&mut self) -> Option<char> {
-        self.peeking = None;
+    /// Defaults to [`LineEndings::Any`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{LineEndings, Parser};
+    /// let mut it = Parser::new("a\r\nb".chars());
+    ///
+    /// it.next();
+    /// it.next();
+    /// assert_eq!((it.line(), it.col()), (1, 1));
+    ///
+    /// it.next();
+    /// assert_eq!((it.line(), it.col()), (2, 0));
+    ///
+    /// let mut it = Parser::new("a\rb".chars()).line_endings(LineEndings::Lf);
+    ///
+    /// it.next();
+    /// it.next();
+    /// assert_eq!(it.line(), 1);
+    /// ```
+    pub fn line_endings(mut self, line_endings: LineEndings) -> Self {
+        self.core.tracker = line_endings;
+        self
+    }
+
+    /// Sets what counts as whitespace for
+    /// [`skip_whitespace()`](Self::skip_whitespace()).
+    ///
+    /// Takes any [`CharPattern`], such as a (capturing) closure or a
+    /// [`CharClass`]. Defaults to [`char::is_whitespace()`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{CharClass, Parser};
+    /// let mut it = Parser::new(" \t\n x".chars()).whitespace(|c| c == ' ' || c == '\t');
+    ///
+    /// assert_eq!(it.skip_whitespace(), 2);
+    /// assert_eq!(it.next(), Some('\n'));
+    ///
+    /// const BLANK: CharClass = CharClass::new(&[], &[' ', '\t']);
+    /// let mut it = Parser::new(" \t\n x".chars()).whitespace(BLANK);
+    ///
+    /// assert_eq!(it.skip_whitespace(), 2);
+    ///
+    /// let newlines = false;
+    /// let mut it = Parser::new(" \n x".chars()).whitespace(move |c: char| {
+    ///     c.is_whitespace() && (newlines || c != '\n')
+    /// });
+    ///
+    /// assert_eq!(it.skip_whitespace(), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn whitespace<P: CharPattern + Send + Sync + 'static>(mut self, is_whitespace: P) -> Self {
+        self.is_whitespace = Box::new(is_whitespace);
+        self
+    }
+
+    /// Sets what counts as whitespace for
+    /// [`skip_whitespace()`](Self::skip_whitespace()).
+    ///
+    /// Defaults to [`char::is_whitespace()`]. With the `alloc` feature, any
+    /// [`CharPattern`] is accepted.
+    #[cfg(not(feature = "alloc"))]
+    pub fn whitespace(mut self, is_whitespace: fn(char) -> bool) -> Self {
+        self.is_whitespace = is_whitespace;
+        self
+    }
 
-        let next = self.iter.next();
+    /// Sets the comment syntax for [`skip_trivia()`](Self::skip_trivia()).
+    ///
+    /// No comments are recognized by default.
+    pub fn comments(mut self, comments: Comments) -> Self {
+        self.comments = comments;
+        self
+    }
 
-        // NOTE: This assumes that all characters (except newline)
-        // advance the col by 1.
-        match next {
-            None => (),
-            Some('\n') => {
-                self.line += 1;
-                self.col = 0;
-            }
-            _ => {
-                self.col += 1;
-            }
-        }
+    /// Sets the name of the parsed source, such as a file path.
+    ///
+    /// It's attached to every [`ParseError`] and included in its display.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let it = Parser::new("".chars()).with_source_name("config.toml");
+    ///
+    /// assert_eq!(it.source_name(), Some("config.toml"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_source_name(mut self, name: impl Into<Arc<str>>) -> Self {
+        self.source_name = Some(name.into());
+        self
+    }
+
+    /// Returns the name set by
+    /// [`with_source_name()`](Self::with_source_name()).
+    #[cfg(feature = "alloc")]
+    pub fn source_name(&self) -> Option<&str> {
+        self.source_name.as_deref()
+    }
 
-        next
+    /// Returns the next item in the inner iterator.
+    ///
+    /// Resets the peeking iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<char> {
+        self.core.next()
     }
 
     /// Peeks the next item in the inner iterator.
@@ -66,7 +617,7 @@ impl<I: Iterator<Item = char> + Clone> Parser<I> {
     /// assert_eq!(it.peek(), None);
     /// ```
     pub fn peek(&mut self) -> Option<char> {
-        self.peeking.get_or_insert_with(|| self.iter.clone()).next()
+        self.core.peek()
     }
 
     /// Peek the `n`th value in the iterator.
@@ -77,14 +628,55 @@ impl<I: Iterator<Item = char> + Clone> Parser<I> {
     ///
     /// assert_eq!(it.peek_nth(2), Some('c'));
     /// assert_eq!(it.next(), Some('a'));
+    /// assert_eq!(it.peek_nth(2), None);
     /// ```
     pub fn peek_nth(&mut self, n: usize) -> Option<char> {
-        n.checked_add(1)
-            .and_then(|n1| (0..n1).flat_map(|_| self.peek()).last())
+        self.core.peek_nth(n)
+    }
+
+    /// Returns the next `n` characters (or fewer at the end of input),
+    /// without consuming them.
+    ///
+    /// Doesn't affect the peeking iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("<=1".chars());
+    ///
+    /// assert_eq!(it.peek_str(2), "<=");
+    /// assert_eq!(it.peek_str(5), "<=1");
+    /// assert_eq!(it.next(), Some('<'));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn peek_str(&self, n: usize) -> String {
+        self.core.iter.clone().take(n).collect()
+    }
+
+    /// Returns `true` if the upcoming input (starting at the base iterator)
+    /// begins with `s`.
+    ///
+    /// Doesn't affect the peeking iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("->x".chars());
+    ///
+    /// assert_eq!(it.peek(), Some('-'));
+    /// assert!(it.starts_with("->"));
+    /// assert!(!it.starts_with("-="));
+    /// assert_eq!(it.peek(), Some('>'));
+    /// ```
+    pub fn starts_with(&self, s: &str) -> bool {
+        let mut ahead = self.core.iter.clone();
+
+        s.chars().all(|c| ahead.next() == Some(c))
     }
 
     /// Advances the base iterator to the be aligned with the peeking one.
     ///
+    /// The position is updated as if the peeked characters were consumed
+    /// with [`next()`](Self::next()).
+    ///
     /// ```rust
     /// # use peeking_iter::Parser;
     /// let mut it = Parser::new("abc".chars());
@@ -94,13 +686,12 @@ impl<I: Iterator<Item = char> + Clone> Parser<I> {
     ///
     /// it.advance_to_peeked();
     ///
+    /// assert_eq!(it.offset(), 2);
     /// assert_eq!(it.next(), Some('c'));
     /// assert_eq!(it.next(), None);
     /// ```
     pub fn advance_to_peeked(&mut self) {
-        if let Some(ref peeking) = self.peeking {
-            self.iter = peeking.clone();
-        }
+        self.core.advance_to_peeked();
     }
 
     /// Rewind the peeking iterator to align with the base one.
@@ -117,7 +708,7 @@ impl<I: Iterator<Item = char> + Clone> Parser<I> {
     /// assert_eq!(it.peek(), Some('a'));
     /// ```
     pub fn rewind_peeking(&mut self) {
-        self.peeking = Some(self.iter.clone())
+        self.core.rewind_peeking();
     }
 
     /// Returns a `Vec<I::Item>` containing all continuous elements that the
@@ -131,64 +722,2015 @@ impl<I: Iterator<Item = char> + Clone> Parser<I> {
     /// assert_eq!(it.peek(), Some('c'));
     /// assert_eq!(it.next(), Some('c'));
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn next_while<F: Fn(char) -> bool>(&mut self, pred: F) -> String {
-        // let mut result = vec![];
-        let mut result = String::new();
+        self.collect_while(pred)
+    }
 
-        loop {
-            match self.peek() {
-                None => break,
-                Some(x) => {
-                    if pred(x) {
-                        result.push(x);
-                        self.next();
-                    } else {
-                        break;
-                    }
-                }
-            }
-        }
+    /// Like [`next_while()`](Self::next_while()), but collects the
+    /// characters into any `B`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("abc1".chars());
+    ///
+    /// let word: Vec<char> = it.collect_while(|c| c.is_alphabetic());
+    ///
+    /// assert_eq!(word, ['a', 'b', 'c']);
+    /// assert_eq!(it.next(), Some('1'));
+    /// ```
+    pub fn collect_while<B, F>(&mut self, pred: F) -> B
+    where
+        B: FromIterator<char>,
+        F: Fn(char) -> bool,
+    {
+        // If `peeking` had already diverged, bring it back
+        self.rewind_peeking();
+
+        let result = core::iter::from_fn(|| {
+            let c = self.peek().filter(|&c| pred(c))?;
+
+            self.next();
+
+            Some(c)
+        })
+        .collect();
 
         self.rewind_peeking();
 
         result
     }
 
-    /// Consumes `self` and returns the inner (base) iterator.
+    /// Like [`next_while()`](Self::next_while()), but returns an error if
+    /// not even one character matches.
+    ///
+    /// **NOTE:** Unlike [`PeekingIter::next_while1()`](crate::PeekingIter::next_while1()),
+    /// this doesn't consume the first character that doesn't match.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("12x".chars());
+    ///
+    /// assert_eq!(it.next_while1(|c| c.is_ascii_digit()), Ok("12".to_string()));
+    ///
+    /// let err = it.next_while1(|c| c.is_ascii_digit()).unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Matching);
+    /// assert_eq!(err.found, Some('x'));
+    /// assert_eq!(err.position.col, 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn next_while1<F: Fn(char) -> bool>(&mut self, pred: F) -> Result<String, ParseError> {
+        self.next_while1_as(pred, Expected::Matching)
+    }
+
+    /// Returns all continuous ASCII digits.
     ///
     /// ```rust
     /// # use peeking_iter::Parser;
-    /// let mut it = Parser::new("abc".chars());
+    /// let mut it = Parser::new("2024-01".chars());
     ///
-    /// it.next();
+    /// assert_eq!(it.digits(), "2024");
+    /// assert_eq!(it.digits(), "");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn digits(&mut self) -> String {
+        self.next_while(|c| c.is_ascii_digit())
+    }
+
+    /// Like [`digits()`](Self::digits()), but returns an error if there
+    /// isn't at least one.
     ///
-    /// assert_eq!(Parser::into_inner(it).collect::<String>(), "bc".to_string());
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("x".chars());
+    ///
+    /// assert_eq!(it.digits_1().unwrap_err().expected, Expected::Description("digit"));
     /// ```
-    pub fn into_inner(value: Self) -> I {
-        value.iter
+    #[cfg(feature = "alloc")]
+    pub fn digits_1(&mut self) -> Result<String, ParseError> {
+        self.next_while1_as(|c| c.is_ascii_digit(), Expected::Description("digit"))
     }
 
-    /// Returns the line number.
+    /// Returns all continuous ASCII hexadecimal digits, in either case.
     ///
     /// ```rust
     /// # use peeking_iter::Parser;
-    /// let mut it = Parser::new("ab\nc".chars());
+    /// let mut it = Parser::new("dEaD beef".chars());
     ///
-    /// it.next();
-    /// it.next();
-    /// it.next();
+    /// assert_eq!(it.hex_digits(), "dEaD");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn hex_digits(&mut self) -> String {
+        self.next_while(|c| c.is_ascii_hexdigit())
+    }
+
+    /// Like [`hex_digits()`](Self::hex_digits()), but returns an error if
+    /// there isn't at least one.
     ///
-    /// assert_eq!(it.line(), 2);
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("0x1F".chars());
+    ///
+    /// assert_eq!(it.expect_str("0x"), Ok(()));
+    /// assert_eq!(it.hex_digits_1(), Ok("1F".to_string()));
+    /// assert!(it.hex_digits_1().is_err());
     /// ```
-    pub fn line(&self) -> u16 {
-        self.line
+    #[cfg(feature = "alloc")]
+    pub fn hex_digits_1(&mut self) -> Result<String, ParseError> {
+        self.next_while1_as(
+            |c| c.is_ascii_hexdigit(),
+            Expected::Description("hex digit"),
+        )
     }
 
-    /// Returns the column number.
+    /// Returns all continuous alphanumeric characters, as defined by
+    /// [`char::is_alphanumeric()`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("ab12é_c".chars());
+    ///
+    /// assert_eq!(it.alphanumerics(), "ab12é");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn alphanumerics(&mut self) -> String {
+        self.next_while(char::is_alphanumeric)
+    }
+
+    /// Like [`alphanumerics()`](Self::alphanumerics()), but returns an
+    /// error if there isn't at least one.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("_".chars());
     ///
-    /// **NOTE:** Every character is assumed to have the column size of 1.
-    pub fn col(&self) -> u16 {
-        self.col
+    /// assert_eq!(it.alphanumerics_1().unwrap_err().found, Some('_'));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn alphanumerics_1(&mut self) -> Result<String, ParseError> {
+        self.next_while1_as(
+            char::is_alphanumeric,
+            Expected::Description("alphanumeric character"),
+        )
+    }
+
+    /// Like [`next_while1()`](Self::next_while1()), with `expected` in the
+    /// error.
+    #[cfg(feature = "alloc")]
+    fn next_while1_as<F: Fn(char) -> bool>(
+        &mut self,
+        pred: F,
+        expected: Expected,
+    ) -> Result<String, ParseError> {
+        // If `peeking` had already diverged, bring it back
+        self.rewind_peeking();
+
+        match self.peek() {
+            Some(x) if pred(x) => {
+                self.rewind_peeking();
+                Ok(self.next_while(pred))
+            }
+            found => {
+                self.rewind_peeking();
+                Err(self.error(found, expected))
+            }
+        }
+    }
+
+    /// Consumes characters up to the first one matching `pattern`.
+    ///
+    /// Returns the consumed characters and whether the terminator was found
+    /// before the end of input. What happens to the terminator itself is
+    /// determined by `terminator`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Parser, Terminator};
+    /// let mut it = Parser::new("a;b;c".chars());
+    ///
+    /// assert_eq!(it.take_until(';', Terminator::Keep), ("a".to_string(), true));
+    /// assert_eq!(it.next(), Some(';'));
+    /// assert_eq!(it.take_until(';', Terminator::Include), ("b;".to_string(), true));
+    /// assert_eq!(it.take_until(|c| c == ';', Terminator::Skip), ("c".to_string(), false));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn take_until<P: CharPattern>(
+        &mut self,
+        pattern: P,
+        terminator: Terminator,
+    ) -> (String, bool) {
+        let mut result = self.next_while(|c| !pattern.matches(c));
+        let found = self.core.iter.clone().next().is_some();
+
+        if found {
+            match terminator {
+                Terminator::Keep => (),
+                Terminator::Include => result.extend(self.next()),
+                Terminator::Skip => {
+                    self.next();
+                }
+            }
+        }
+
+        (result, found)
+    }
+
+    /// Consumes the rest of the current line.
+    ///
+    /// Line endings are recognized according to
+    /// [`line_endings()`](Self::line_endings()). What happens to the line
+    /// ending itself is determined by `terminator`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Parser, Terminator};
+    /// let mut it = Parser::new("#!/bin/sh\r\necho\nexit".chars());
+    ///
+    /// assert_eq!(it.take_line(Terminator::Skip), "#!/bin/sh");
+    /// assert_eq!(it.line(), 2);
+    /// assert_eq!(it.take_line(Terminator::Include), "echo\n");
+    /// assert_eq!(it.take_line(Terminator::Keep), "exit");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn take_line(&mut self, terminator: Terminator) -> String {
+        let mut line = String::new();
+
+        self.scan_line(terminator, |c| line.push(c));
+
+        line
+    }
+
+    /// Returns an iterator over the remaining lines, each paired with the
+    /// position it starts at.
+    ///
+    /// Line endings are skipped. The parser is advanced along with the
+    /// iterator, so it can be used for char-level parsing between lines.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("a = 1\r\nb = 2\n".chars());
+    /// let mut lines = it.lines();
+    ///
+    /// let (pos, line) = lines.next().unwrap();
+    /// assert_eq!((pos.line, line.as_str()), (1, "a = 1"));
+    ///
+    /// let (pos, line) = lines.next().unwrap();
+    /// assert_eq!((pos.line, pos.offset, line.as_str()), (2, 7, "b = 2"));
+    ///
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn lines(&mut self) -> Lines<'_, I> {
+        Lines::new(self)
+    }
+
+    /// Returns the whole line currently being parsed, without its line
+    /// ending.
+    ///
+    /// Useful for showing context in error messages.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("a = 1\nb = ?\n".chars());
+    ///
+    /// it.take_line(Default::default());
+    /// it.next_while(|c| c != '?');
+    ///
+    /// assert_eq!(it.current_line(), "b = ?");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn current_line(&self) -> String {
+        Parser::new(self.core.state.line_start.clone())
+            .line_endings(self.core.tracker)
+            .take_line(Terminator::Keep)
+    }
+
+    /// Returns the number of spaces and tabs the current line starts with.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Parser, Terminator};
+    /// let mut it = Parser::new("if x:\n    y".chars());
+    ///
+    /// assert_eq!(it.indent(), 0);
+    ///
+    /// it.take_line(Terminator::Skip);
+    /// assert_eq!(it.indent(), 4);
+    /// ```
+    pub fn indent(&self) -> usize {
+        self.core
+            .state
+            .line_start
+            .clone()
+            .take_while(|&c| c == ' ' || c == '\t')
+            .count()
+    }
+
+    /// Compares the [`indent()`](Self::indent()) of the current line with
+    /// the stack of enclosing levels, updating the stack.
+    ///
+    /// Should be called once per line, usually at its start. Returns an
+    /// error at the current position if a dedent doesn't match any
+    /// enclosing level.
+    ///
+    /// **NOTE:** The stack isn't restored by
+    /// [`rollback()`](Self::rollback()).
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{IndentChange, Parser, Terminator};
+    /// let mut it = Parser::new("a\n  b\n    c\na\n  b\n c".chars());
+    /// let mut changes = Vec::new();
+    ///
+    /// while !it.at_end() {
+    ///     changes.push(it.update_indent());
+    ///     it.take_line(Terminator::Skip);
+    /// }
+    ///
+    /// assert_eq!(changes[..5], [
+    ///     Ok(IndentChange::Same),
+    ///     Ok(IndentChange::Indent),
+    ///     Ok(IndentChange::Indent),
+    ///     Ok(IndentChange::Dedent(2)),
+    ///     Ok(IndentChange::Indent),
+    /// ]);
+    /// assert!(changes[5].is_err());
+    /// assert_eq!(it.indent_depth(), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn update_indent(&mut self) -> Result<IndentChange, ParseError> {
+        let indent = self.indent();
+        let current = self.indents.last().copied().unwrap_or(0);
+
+        if indent > current {
+            self.indents.push(indent);
+
+            return Ok(IndentChange::Indent);
+        }
+
+        let levels = self
+            .indents
+            .iter()
+            .rev()
+            .take_while(|&&x| x > indent)
+            .count();
+        let outer = self.indents.len() - levels;
+
+        let enclosing = outer.checked_sub(1).map_or(0, |i| self.indents[i]);
+
+        if enclosing != indent {
+            let found = self.core.iter.clone().next();
+
+            return Err(self.error(
+                found,
+                Expected::Description("indentation of an enclosing block"),
+            ));
+        }
+
+        self.indents.truncate(outer);
+
+        Ok(match levels {
+            0 => IndentChange::Same,
+            n => IndentChange::Dedent(n),
+        })
+    }
+
+    /// Returns the number of blocks enclosing the current line, as tracked
+    /// by [`update_indent()`](Self::update_indent()).
+    #[cfg(feature = "alloc")]
+    pub fn indent_depth(&self) -> usize {
+        self.indents.len()
+    }
+
+    /// Consumes the rest of the current line, passing each character of it
+    /// to `push`.
+    fn scan_line<F: FnMut(char)>(&mut self, terminator: Terminator, mut push: F) {
+        let ending = loop {
+            match self.line_ending_len() {
+                0 => match self.next() {
+                    Some(c) => push(c),
+                    None => break 0,
+                },
+                len => break len,
+            }
+        };
+
+        for _ in 0..ending {
+            match terminator {
+                Terminator::Keep => (),
+                Terminator::Include => self.next().into_iter().for_each(&mut push),
+                Terminator::Skip => {
+                    self.next();
+                }
+            }
+        }
+    }
+
+    /// Parses an integer in the given `radix`, with an optional sign.
+    ///
+    /// On error, the parser is left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("-ff 300 x".chars());
+    ///
+    /// assert_eq!(it.parse_integer::<i32>(16), Ok(-255));
+    /// it.skip_whitespace();
+    ///
+    /// let err = it.parse_integer::<u8>(10).unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Description("integer in range"));
+    /// assert_eq!(it.parse_integer::<u16>(10), Ok(300));
+    /// it.skip_whitespace();
+    ///
+    /// let err = it.parse_integer::<u16>(10).unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Description("digit"));
+    /// assert_eq!(err.found, Some('x'));
+    /// ```
+    pub fn parse_integer<T: Integer>(&mut self, radix: u32) -> Result<T, ParseError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range from 2 to 36"
+        );
+
+        self.rewind_peeking();
+
+        let mut negative = false;
+        let mut len = 0;
+        let mut next = self.peek();
+
+        match next {
+            Some('-') if T::SIGNED => {
+                negative = true;
+                len += 1;
+                next = self.peek();
+            }
+            Some('+') => {
+                len += 1;
+                next = self.peek();
+            }
+            _ => (),
+        }
+
+        let mut value = T::ZERO;
+        let mut digits = 0;
+        let mut overflow = false;
+
+        while let Some(digit) = next.and_then(|c| c.to_digit(radix)) {
+            match value.push_digit(radix, digit, negative) {
+                Some(v) => value = v,
+                None => overflow = true,
+            }
+
+            digits += 1;
+            next = self.peek();
+        }
+
+        self.rewind_peeking();
+
+        if overflow {
+            return Err(self.overflow_error());
+        }
+        if digits == 0 {
+            return Err(self.error(next, Expected::Description("digit")));
+        }
+
+        for _ in 0..len + digits {
+            self.next();
+        }
+
+        Ok(value)
+    }
+
+    /// Parses a floating point literal: digits, optionally followed by a
+    /// fraction and an exponent, with an optional sign.
+    ///
+    /// Returns the value and the span of the literal. Never consumes past
+    /// the literal: a `.` not followed by a digit is left in the input. On
+    /// error, the parser is left untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("-1.5e3 2.x 1e+".chars());
+    ///
+    /// let (value, span) = it.parse_float().unwrap();
+    ///
+    /// assert_eq!(value, -1500.0);
+    /// assert_eq!(span.len(), 6);
+    /// it.skip_whitespace();
+    ///
+    /// assert_eq!(it.parse_float().map(|(v, _)| v), Ok(2.0));
+    /// assert_eq!(it.next(), Some('.'));
+    /// it.next_while(|c| c != ' ');
+    /// it.skip_whitespace();
+    ///
+    /// let err = it.parse_float().unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Description("exponent digit"));
+    /// assert_eq!(err.found, None);
+    ///
+    /// // An exponent can't follow a `.` without a fraction
+    /// let mut it = Parser::new("1.e5 7.E".chars());
+    ///
+    /// let (value, span) = it.parse_float().unwrap();
+    ///
+    /// assert_eq!((value, span.len()), (1.0, 1));
+    /// assert_eq!(it.next_while(|c| c != ' '), ".e5");
+    /// it.skip_whitespace();
+    ///
+    /// assert_eq!(it.parse_float().map(|(v, _)| v), Ok(7.0));
+    /// assert_eq!(it.next_while(|_| true), ".E");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_float(&mut self) -> Result<(f64, Span), ParseError> {
+        let start = self.core.state.pos;
+        let (literal, len, _) = self.scan_number(false)?;
+
+        for _ in 0..len {
+            self.next();
+        }
+
+        // The literal is made up only of valid float syntax
+        let value = literal.parse().unwrap_or_default();
+
+        Ok((value, self.span_since(start)))
+    }
+
+    /// Parses an integer or floating point literal, which may contain `_`
+    /// digit separators.
+    ///
+    /// A literal with a fraction or an exponent is a [`Number::Float`],
+    /// otherwise it is a [`Number::Int`]. Returns the number and the span of
+    /// the literal. Never consumes past the literal. On error, the parser is
+    /// left untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Number, Parser};
+    /// let mut it = Parser::new("1_000 2.5e1_0 -7.".chars());
+    ///
+    /// let (number, span) = it.parse_number().unwrap();
+    ///
+    /// assert_eq!(number, Number::Int(1000));
+    /// assert_eq!(span.len(), 5);
+    /// it.skip_whitespace();
+    ///
+    /// assert_eq!(it.parse_number().map(|(n, _)| n), Ok(Number::Float(2.5e10)));
+    /// it.skip_whitespace();
+    ///
+    /// assert_eq!(it.parse_number().map(|(n, _)| n), Ok(Number::Int(-7)));
+    /// assert_eq!(it.next(), Some('.'));
+    ///
+    /// // Method calls and the like on an integer are left alone
+    /// let mut it = Parser::new("3.e 1.e5".chars());
+    ///
+    /// assert_eq!(it.parse_number().map(|(n, _)| n), Ok(Number::Int(3)));
+    /// assert_eq!(it.next_while(|c| c != ' '), ".e");
+    /// it.skip_whitespace();
+    ///
+    /// let (number, span) = it.parse_number().unwrap();
+    ///
+    /// assert_eq!((number, span.len()), (Number::Int(1), 1));
+    /// assert_eq!(it.next_while(|_| true), ".e5");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_number(&mut self) -> Result<(Number, Span), ParseError> {
+        let start = self.core.state.pos;
+        let (literal, len, is_float) = self.scan_number(true)?;
+
+        // The literal is made up only of valid number syntax
+        let number = if is_float {
+            Number::Float(literal.parse().unwrap_or_default())
+        } else {
+            match literal.parse() {
+                Ok(n) => Number::Int(n),
+                Err(_) => return Err(self.overflow_error()),
+            }
+        };
+
+        for _ in 0..len {
+            self.next();
+        }
+
+        Ok((number, self.span_since(start)))
+    }
+
+    /// Scans a number literal with the peeking iterator.
+    ///
+    /// Returns the literal (without separators), its length in characters
+    /// and whether it has a fraction or an exponent. Leaves the peeking
+    /// iterator rewound.
+    #[cfg(feature = "alloc")]
+    fn scan_number(&mut self, separators: bool) -> Result<(String, usize, bool), ParseError> {
+        let mut literal = String::new();
+        let mut len = 0;
+        let mut is_float = false;
+
+        // If `peeking` had already diverged, bring it back
+        self.rewind_peeking();
+
+        let mut next = self.peek();
+
+        if let Some(sign @ ('-' | '+')) = next {
+            literal.push(sign);
+            len += 1;
+            next = self.peek();
+        }
+
+        if !next.is_some_and(|c| c.is_ascii_digit()) {
+            self.rewind_peeking();
+            return Err(self.error(next, Expected::Description("digit")));
+        }
+
+        len += self.scan_digits(&mut next, &mut literal, separators);
+
+        // Only a `.` followed by a digit belongs to the literal
+        if next == Some('.') {
+            next = self.peek();
+
+            if next.is_some_and(|c| c.is_ascii_digit()) {
+                literal.push('.');
+                len += 1;
+                is_float = true;
+
+                len += self.scan_digits(&mut next, &mut literal, separators);
+            } else {
+                // The literal ends before the `.`, so no exponent follows
+                next = Some('.');
+            }
+        }
+
+        if let Some(e @ ('e' | 'E')) = next {
+            literal.push(e);
+            len += 1;
+            is_float = true;
+            next = self.peek();
+
+            if let Some(sign @ ('-' | '+')) = next {
+                literal.push(sign);
+                len += 1;
+                next = self.peek();
+            }
+
+            if !next.is_some_and(|c| c.is_ascii_digit()) {
+                self.rewind_peeking();
+                return Err(self.error(next, Expected::Description("exponent digit")));
+            }
+
+            len += self.scan_digits(&mut next, &mut literal, separators);
+        }
+
+        self.rewind_peeking();
+
+        Ok((literal, len, is_float))
+    }
+
+    /// Peeks decimal digits (and separators, if enabled), starting with
+    /// `next`, and pushes them to `literal`.
+    ///
+    /// Returns the number of characters peeked, leaving `next` at the first
+    /// one that doesn't belong to the literal.
+    #[cfg(feature = "alloc")]
+    fn scan_digits(
+        &mut self,
+        next: &mut Option<char>,
+        literal: &mut String,
+        separators: bool,
+    ) -> usize {
+        let mut len = 0;
+
+        while let Some(c) = next.filter(|c| c.is_ascii_digit() || (separators && *c == '_')) {
+            if c != '_' {
+                literal.push(c);
+            }
+
+            len += 1;
+            *next = self.peek();
+        }
+
+        len
+    }
+
+    /// Parses an identifier.
+    ///
+    /// With the `unicode-ident` feature, identifiers follow the Unicode
+    /// `XID_Start`/`XID_Continue` rules (with `_` allowed at the start).
+    /// Otherwise, only ASCII letters, digits and `_` are accepted.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("_foo1 1x".chars());
+    ///
+    /// assert_eq!(it.parse_identifier(), Ok("_foo1".to_string()));
+    /// it.skip_whitespace();
+    ///
+    /// let err = it.parse_identifier().unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Description("identifier"));
+    /// assert_eq!(err.found, Some('1'));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_identifier(&mut self) -> Result<String, ParseError> {
+        #[cfg(feature = "unicode-ident")]
+        let (is_start, is_continue) = (
+            |c: char| c == '_' || unicode_ident::is_xid_start(c),
+            unicode_ident::is_xid_continue,
+        );
+        #[cfg(not(feature = "unicode-ident"))]
+        let (is_start, is_continue) = (
+            |c: char| c == '_' || c.is_ascii_alphabetic(),
+            |c: char| c == '_' || c.is_ascii_alphanumeric(),
+        );
+
+        // If `peeking` had already diverged, bring it back
+        self.rewind_peeking();
+
+        match self.peek() {
+            Some(c) if is_start(c) => {
+                self.next();
+
+                let mut ident = self.next_while(is_continue);
+                ident.insert(0, c);
+
+                Ok(ident)
+            }
+            found => {
+                self.rewind_peeking();
+                Err(self.error(found, Expected::Description("identifier")))
+            }
+        }
+    }
+
+    /// Parses a string literal delimited by `quote`, decoding escape
+    /// sequences.
+    ///
+    /// Supported escapes are `\\`, `\"`, `\'`, `\n`, `\r`, `\t`, `\0`,
+    /// `\u{...}` and a backslash followed by `quote`.
+    ///
+    /// On error, the parser is left untouched, while the error points at
+    /// the offending character.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new(r#""a\"\u{e9}\n" 'b"#.chars());
+    ///
+    /// assert_eq!(it.parse_string('"'), Ok("a\"é\n".to_string()));
+    /// it.skip_whitespace();
+    ///
+    /// let err = it.parse_string('\'').unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Char('\''));
+    /// assert_eq!(err.found, None);
+    /// assert_eq!(err.position.col, 16);
+    /// assert_eq!(it.col(), 14);
+    ///
+    /// let mut it = Parser::new(r#"'say \"hi\"'"#.chars());
+    ///
+    /// assert_eq!(it.parse_string('\''), Ok("say \"hi\"".to_string()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_string(&mut self, quote: char) -> Result<String, ParseError> {
+        let result = self.attempt(|p| p.parse_string_inner(quote));
+
+        self.rewind_peeking();
+
+        result
+    }
+
+    #[cfg(feature = "alloc")]
+    fn parse_string_inner(&mut self, quote: char) -> Result<String, ParseError> {
+        self.expect(quote)?;
+
+        let mut result = String::new();
+
+        loop {
+            let pos = self.core.state.pos;
+
+            match self.next() {
+                None => return Err(self.error_at(pos, None, Expected::Char(quote))),
+                Some(c) if c == quote => return Ok(result),
+                Some('\\') => {
+                    let pos = self.core.state.pos;
+                    match self.next() {
+                        Some('\\') => result.push('\\'),
+                        Some('n') => result.push('\n'),
+                        Some('r') => result.push('\r'),
+                        Some('t') => result.push('\t'),
+                        Some('0') => result.push('\0'),
+                        Some(c @ ('"' | '\'')) => result.push(c),
+                        Some(c) if c == quote => result.push(c),
+                        Some('u') => {
+                            self.expect('{')?;
+
+                            let code = self.next_while(|c| c.is_ascii_hexdigit());
+                            let c = u32::from_str_radix(&code, 16)
+                                .ok()
+                                .filter(|_| code.len() <= 6)
+                                .and_then(char::from_u32);
+
+                            match c {
+                                Some(c) => result.push(c),
+                                None => return Err(self.escape_error(pos, Some('u'))),
+                            }
+
+                            self.expect('}')?;
+                        }
+                        found => return Err(self.escape_error(pos, found)),
+                    }
+                }
+                Some(c) => result.push(c),
+            }
+        }
+    }
+
+    /// Parses `item (sep item)*`, returning the items with their spans.
+    ///
+    /// An item that fails where it's optional (the first one with `min` 0,
+    /// or one after a trailing separator) is rolled back and ends the list.
+    /// Whitespace around separators is left to `item`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Parser, Separated};
+    /// let mut it = Parser::new("[1,2,3,]".chars());
+    /// let config = Separated {
+    ///     trailing: true,
+    ///     min: 1,
+    /// };
+    ///
+    /// it.expect('[').unwrap();
+    ///
+    /// let items = it
+    ///     .parse_separated(|p| p.parse_integer::<u8>(10), ',', config)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(items.iter().map(|(x, _)| *x).collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert_eq!(items[1].1.range(), 3..4);
+    /// assert_eq!(it.next(), Some(']'));
+    ///
+    /// let mut it = Parser::new("1,2,".chars());
+    ///
+    /// assert!(it
+    ///     .parse_separated(|p| p.parse_integer::<u8>(10), ',', Separated::default())
+    ///     .is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_separated<T, F>(
+        &mut self,
+        mut item: F,
+        sep: char,
+        config: Separated,
+    ) -> Result<Vec<(T, Span)>, ParseError>
+    where
+        F: FnMut(&mut Self) -> Result<T, ParseError>,
+    {
+        let mut items = Vec::new();
+
+        loop {
+            let optional = items.len() >= config.min && (items.is_empty() || config.trailing);
+            let start = self.core.state.pos;
+
+            match self.attempt(&mut item) {
+                Ok(x) => items.push((x, self.span_since(start))),
+                Err(_) if optional => break,
+                Err(e) => return Err(e),
+            }
+
+            if !self.consume_if(sep) {
+                break;
+            }
+        }
+
+        // Too few items can only be due to a missing separator
+        if items.len() < config.min {
+            self.expect(sep)?;
+        }
+
+        Ok(items)
+    }
+
+    /// Consumes a region delimited by `open` and `close`, returning its
+    /// contents without the outer delimiters.
+    ///
+    /// Nested delimiters must be balanced. On a missing `close`, returns an
+    /// error at the end of the input and leaves the parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("{ a { b } } c".chars());
+    ///
+    /// assert_eq!(it.take_balanced('{', '}'), Ok(" a { b } ".to_string()));
+    /// assert_eq!(it.next(), Some(' '));
+    ///
+    /// let mut it = Parser::new("(a (b)".chars());
+    /// let err = it.take_balanced('(', ')').unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Char(')'));
+    /// assert_eq!(err.found, None);
+    /// assert_eq!(err.position.col, 6);
+    /// assert_eq!(it.col(), 0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn take_balanced(&mut self, open: char, close: char) -> Result<String, ParseError> {
+        self.take_balanced_quoted(open, close, "")
+    }
+
+    /// Like [`take_balanced()`](Self::take_balanced()), but delimiters inside
+    /// string literals quoted by any of `quotes` aren't counted.
+    ///
+    /// A backslash inside a literal escapes the next character.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new(r#"(f(")") '\'(')"#.chars());
+    ///
+    /// assert_eq!(
+    ///     it.take_balanced_quoted('(', ')', "\"'"),
+    ///     Ok(r#"f(")") '\'('"#.to_string())
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn take_balanced_quoted(
+        &mut self,
+        open: char,
+        close: char,
+        quotes: &str,
+    ) -> Result<String, ParseError> {
+        let result = self.attempt(|p| p.take_balanced_inner(open, close, quotes));
+
+        self.rewind_peeking();
+
+        result
+    }
+
+    #[cfg(feature = "alloc")]
+    fn take_balanced_inner(
+        &mut self,
+        open: char,
+        close: char,
+        quotes: &str,
+    ) -> Result<String, ParseError> {
+        self.expect(open)?;
+
+        let mut result = String::new();
+        let mut depth = 0_usize;
+        let mut quote = None;
+
+        loop {
+            let c = match self.next() {
+                Some(c) => c,
+                None => {
+                    let expected = Expected::Char(quote.unwrap_or(close));
+
+                    return Err(self.error(None, expected));
+                }
+            };
+
+            match quote {
+                Some(q) if c == q => quote = None,
+                Some(_) if c == '\\' => {
+                    result.push(c);
+                    result.extend(self.next());
+                    continue;
+                }
+                Some(_) => (),
+                None if quotes.contains(c) => quote = Some(c),
+                // Checked before `open`, in case they're the same
+                None if c == close && depth == 0 => return Ok(result),
+                None if c == close => depth -= 1,
+                None if c == open => depth += 1,
+                None => (),
+            }
+
+            result.push(c);
+        }
+    }
+
+    /// Consumes input until one of the strings in `sync` is next (or the
+    /// input ends), returning what was skipped and its span.
+    ///
+    /// The synchronization string itself isn't consumed. Meant for error
+    /// recovery, to continue parsing after an error at a known boundary.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("let = 1 + ; let y = 2;".chars());
+    ///
+    /// it.expect_str("let ").unwrap();
+    /// assert!(it.parse_identifier().is_err());
+    ///
+    /// let (skipped, span) = it.skip_to_sync(&[";", "\n"]);
+    ///
+    /// assert_eq!(skipped, "= 1 + ");
+    /// assert_eq!(span.range(), 4..10);
+    /// assert_eq!(it.next(), Some(';'));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn skip_to_sync(&mut self, sync: &[&str]) -> (String, Span) {
+        let start = self.core.state.pos;
+        let mut skipped = String::new();
+
+        while !sync.iter().any(|s| self.starts_with(s)) {
+            match self.next() {
+                Some(c) => skipped.push(c),
+                None => break,
+            }
+        }
+
+        self.rewind_peeking();
+
+        (skipped, self.span_since(start))
+    }
+
+    /// Records a recoverable error, so that parsing can continue and all
+    /// errors can be reported at the end.
+    ///
+    /// Recorded errors are kept by [`rollback()`](Self::rollback()).
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("1;x;3;y".chars());
+    /// let mut values = Vec::new();
+    ///
+    /// while !it.at_end() {
+    ///     match it.parse_integer::<u8>(10) {
+    ///         Ok(x) => values.push(x),
+    ///         Err(e) => {
+    ///             it.record_error(e);
+    ///             it.skip_to_sync(&[";"]);
+    ///         }
+    ///     }
+    ///
+    ///     it.consume_if(';');
+    /// }
+    ///
+    /// assert_eq!(values, [1, 3]);
+    /// assert_eq!(it.errors().len(), 2);
+    /// assert_eq!(it.errors()[1].position.col, 6);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn record_error(&mut self, error: ParseError) {
+        self.errors.push(error);
+    }
+
+    /// Returns the errors recorded with
+    /// [`record_error()`](Self::record_error()), in order.
+    #[cfg(feature = "alloc")]
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    /// Consumes `self` and returns the recorded errors.
+    #[cfg(feature = "alloc")]
+    pub fn into_errors(self) -> Vec<ParseError> {
+        self.errors
+    }
+
+    /// Creates an error of kind [`ErrorKind::Custom`] at the current
+    /// position, for failures that the built-in helpers don't describe.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("0".chars());
+    ///
+    /// let err = it.custom_error("port can't be 0");
+    ///
+    /// assert_eq!(err.to_string(), "1:1: port can't be 0");
+    /// ```
+    pub fn custom_error(&self, message: &'static str) -> ParseError {
+        let found = self.core.iter.clone().next();
+
+        ParseError {
+            kind: ErrorKind::Custom,
+            ..self.error(found, Expected::Description(message))
+        }
+    }
+
+    /// Consumes a byte order mark (`U+FEFF`) if the input continues with
+    /// one.
+    ///
+    /// The mark doesn't take up a column, but still counts towards
+    /// [`offset()`](Self::offset()). Returns whether it was skipped.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("\u{feff}key".chars());
+    ///
+    /// assert!(it.skip_bom());
+    /// assert_eq!((it.col(), it.offset()), (0, 3));
+    /// assert!(!it.skip_bom());
+    /// assert_eq!(it.expect_str("key"), Ok(()));
+    /// ```
+    pub fn skip_bom(&mut self) -> bool {
+        let col = self.core.state.pos.col;
+
+        if !self.consume_if('\u{feff}') {
+            return false;
+        }
+
+        self.core.state.pos.col = col;
+
+        true
+    }
+
+    /// Consumes all consecutive whitespace characters.
+    ///
+    /// What counts as whitespace can be set with
+    /// [`whitespace()`](Self::whitespace()).
+    ///
+    /// Returns the number of characters skipped.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("  \n x".chars());
+    ///
+    /// assert_eq!(it.skip_whitespace(), 4);
+    /// assert_eq!((it.line(), it.col()), (2, 1));
+    /// assert_eq!(it.next(), Some('x'));
+    /// ```
+    pub fn skip_whitespace(&mut self) -> usize {
+        let mut skipped = 0;
+
+        // If `peeking` had already diverged, bring it back
+        self.rewind_peeking();
+
+        while self
+            .core
+            .peek()
+            .is_some_and(|c| self.is_whitespace.matches(c))
+        {
+            self.next();
+            skipped += 1;
+        }
+
+        self.rewind_peeking();
+
+        skipped
+    }
+
+    /// Consumes all consecutive whitespace and comments.
+    ///
+    /// Comment syntax can be set with [`comments()`](Self::comments()).
+    /// Line comments end before the line ending, block comments after the
+    /// closing delimiter. Block comments don't nest.
+    ///
+    /// Returns the number of characters skipped, or an error at the end of
+    /// input if a block comment is unterminated.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Comments, Expected, Parser};
+    /// let comments = Comments {
+    ///     line: &["//", "#"],
+    ///     block: &[("/*", "*/")],
+    /// };
+    /// let mut it = Parser::new(" # a\n/* b\n */ x /* c".chars()).comments(comments);
+    ///
+    /// assert_eq!(it.skip_trivia(), Ok(14));
+    /// assert_eq!((it.line(), it.col()), (3, 4));
+    /// assert_eq!(it.next(), Some('x'));
+    ///
+    /// let err = it.skip_trivia().unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Description("end of comment"));
+    /// assert_eq!(err.found, None);
+    /// ```
+    ///
+    /// Line comments end at the line endings set with
+    /// [`line_endings()`](Self::line_endings()):
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Comments, LineEndings, Parser};
+    /// let comments = Comments {
+    ///     line: &["#"],
+    ///     block: &[],
+    /// };
+    /// let mut it = Parser::new("# a\rb\nc".chars())
+    ///     .line_endings(LineEndings::Lf)
+    ///     .comments(comments);
+    ///
+    /// assert_eq!(it.skip_trivia(), Ok(6));
+    /// assert_eq!(it.next(), Some('c'));
+    /// ```
+    pub fn skip_trivia(&mut self) -> Result<usize, ParseError> {
+        let mut skipped = 0;
+
+        loop {
+            skipped += self.skip_whitespace();
+
+            if let Some(prefix) = self.comments.line.iter().find(|p| self.starts_with(p)) {
+                skipped += prefix.chars().count();
+
+                for _ in prefix.chars() {
+                    self.next();
+                }
+
+                while self.line_ending_len() == 0 && self.next().is_some() {
+                    skipped += 1;
+                }
+            } else if let Some((open, close)) = self
+                .comments
+                .block
+                .iter()
+                .find(|(o, _)| self.starts_with(o))
+            {
+                skipped += open.chars().count();
+
+                for _ in open.chars() {
+                    self.next();
+                }
+
+                while !self.starts_with(close) {
+                    if self.next().is_none() {
+                        return Err(self.error(None, Expected::Description("end of comment")));
+                    }
+
+                    skipped += 1;
+                }
+
+                skipped += close.chars().count();
+
+                for _ in close.chars() {
+                    self.next();
+                }
+            } else {
+                return Ok(skipped);
+            }
+        }
+    }
+
+    /// Consumes the next character if it is `c`.
+    ///
+    /// Otherwise returns an error and leaves the parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser, Position};
+    /// let mut it = Parser::new("(x".chars());
+    ///
+    /// assert_eq!(it.expect('('), Ok('('));
+    ///
+    /// let err = it.expect(')').unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Char(')'));
+    /// assert_eq!(err.found, Some('x'));
+    /// assert_eq!(err.position, Position { line: 1, col: 1, offset: 1 });
+    /// assert_eq!(it.next(), Some('x'));
+    /// ```
+    pub fn expect(&mut self, c: char) -> Result<char, ParseError> {
+        self.next_matching(|x| x == c, Expected::Char(c))
+    }
+
+    /// Consumes `s` if the input starts with it.
+    ///
+    /// Otherwise returns an error at the current position, with `found`
+    /// being the first character that doesn't match, and leaves the parser
+    /// untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("let x".chars());
+    ///
+    /// let err = it.expect_str("lex").unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::Str("lex".to_string()));
+    /// assert_eq!(err.found, Some('t'));
+    /// assert_eq!(it.col(), 0);
+    ///
+    /// assert_eq!(it.expect_str("let"), Ok(()));
+    /// assert_eq!(it.next(), Some(' '));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn expect_str(&mut self, s: &str) -> Result<(), ParseError> {
+        self.expect_str_by(s, |x, c| x == c)
+    }
+
+    /// Like [`expect_str()`](Self::expect_str()), but ignores ASCII case.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("Select *".chars());
+    ///
+    /// assert!(it.expect_str_ignore_ascii_case("FROM").is_err());
+    /// assert_eq!(it.expect_str_ignore_ascii_case("SELECT"), Ok(()));
+    /// assert_eq!(it.next(), Some(' '));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn expect_str_ignore_ascii_case(&mut self, s: &str) -> Result<(), ParseError> {
+        self.expect_str_by(s, |x, c| x.eq_ignore_ascii_case(&c))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn expect_str_by(&mut self, s: &str, eq: fn(char, char) -> bool) -> Result<(), ParseError> {
+        self.rewind_peeking();
+
+        for c in s.chars() {
+            match self.peek() {
+                Some(x) if eq(x, c) => (),
+                found => {
+                    self.rewind_peeking();
+                    return Err(self.error(found, Expected::Str(s.into())));
+                }
+            }
+        }
+
+        self.advance_to_peeked();
+
+        Ok(())
+    }
+
+    /// Like [`expect()`](Self::expect()), but ignores ASCII case.
+    ///
+    /// Returns the character as found in the input.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("Xy".chars());
+    ///
+    /// assert_eq!(it.expect_ignore_ascii_case('x'), Ok('X'));
+    /// assert!(it.expect_ignore_ascii_case('x').is_err());
+    /// ```
+    pub fn expect_ignore_ascii_case(&mut self, c: char) -> Result<char, ParseError> {
+        self.next_matching(|x| x.eq_ignore_ascii_case(&c), Expected::Char(c))
+    }
+
+    /// Consumes the next character if it is in `set`.
+    ///
+    /// Otherwise returns an error and leaves the parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("*x".chars());
+    ///
+    /// assert_eq!(it.one_of("+-*/"), Ok('*'));
+    ///
+    /// let err = it.one_of("+-*/").unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::OneOf("+-*/"));
+    /// assert_eq!(err.found, Some('x'));
+    /// ```
+    pub fn one_of(&mut self, set: &'static str) -> Result<char, ParseError> {
+        self.next_matching(|c| set.contains(c), Expected::OneOf(set))
+    }
+
+    /// Consumes the next character if it is not in `set`.
+    ///
+    /// Otherwise (including at the end of input) returns an error and
+    /// leaves the parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Expected, Parser};
+    /// let mut it = Parser::new("a\"".chars());
+    ///
+    /// assert_eq!(it.none_of("\"\\"), Ok('a'));
+    ///
+    /// let err = it.none_of("\"\\").unwrap_err();
+    ///
+    /// assert_eq!(err.expected, Expected::NoneOf("\"\\"));
+    /// assert_eq!(err.found, Some('"'));
+    /// ```
+    pub fn none_of(&mut self, set: &'static str) -> Result<char, ParseError> {
+        self.next_matching(|c| !set.contains(c), Expected::NoneOf(set))
+    }
+
+    /// Consumes the next character if it is in `class`.
+    ///
+    /// Otherwise returns an error and leaves the parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{CharClass, Expected, Parser};
+    /// const DIGIT: CharClass = CharClass::new(&['0'..='9'], &[]);
+    ///
+    /// let mut it = Parser::new("1a".chars());
+    ///
+    /// assert_eq!(it.expect_class(&DIGIT), Ok('1'));
+    /// assert_eq!(it.expect_class(&DIGIT).unwrap_err().expected, Expected::Class(DIGIT));
+    /// ```
+    pub fn expect_class(&mut self, class: &CharClass) -> Result<char, ParseError> {
+        self.next_matching(|c| class.contains(c), Expected::Class(class.clone()))
+    }
+
+    /// Returns all continuous characters in `class`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{CharClass, Parser};
+    /// const HEX: CharClass = CharClass::new(&['0'..='9', 'a'..='f'], &[]);
+    ///
+    /// let mut it = Parser::new("c0ffee!".chars());
+    ///
+    /// assert_eq!(it.next_while_class(&HEX), "c0ffee");
+    /// assert_eq!(it.next(), Some('!'));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn next_while_class(&mut self, class: &CharClass) -> String {
+        self.next_while(|c| class.contains(c))
+    }
+
+    /// Consumes the next character if it is `c`.
+    ///
+    /// Returns whether it was consumed. Lighter than
+    /// [`expect()`](Self::expect()), as no error is constructed.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("-1".chars());
+    ///
+    /// assert!(!it.consume_if('+'));
+    /// assert!(it.consume_if('-'));
+    /// assert_eq!(it.next(), Some('1'));
+    /// ```
+    pub fn consume_if(&mut self, c: char) -> bool {
+        self.rewind_peeking();
+
+        if self.peek() == Some(c) {
+            self.next();
+            true
+        } else {
+            self.rewind_peeking();
+            false
+        }
+    }
+
+    /// Consumes `self` and returns the inner (base) iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("abc".chars());
+    ///
+    /// it.next();
+    ///
+    /// assert_eq!(Parser::into_inner(it).collect::<String>(), "bc".to_string());
+    /// ```
+    pub fn into_inner(value: Self) -> I {
+        value.core.iter
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn inner(&self) -> &I {
+        &self.core.iter
+    }
+
+    /// Returns the line number.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("ab\nc".chars());
+    ///
+    /// it.next();
+    /// it.next();
+    /// it.next();
+    ///
+    /// assert_eq!(it.line(), 2);
+    /// ```
+    pub fn line(&self) -> usize {
+        self.core.state.pos.line
+    }
+
+    /// Returns the column number.
+    ///
+    /// **NOTE:** Every character is assumed to have the column size of 1,
+    /// unless the `unicode-width` feature is enabled, in which case the
+    /// display width is used. Tabs and other control characters always take
+    /// up 1 column.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("日本".chars());
+    ///
+    /// it.next();
+    ///
+    /// # #[cfg(feature = "unicode-width")]
+    /// assert_eq!(it.col(), 2);
+    /// # #[cfg(not(feature = "unicode-width"))]
+    /// assert_eq!(it.col(), 1);
+    ///
+    /// let mut it = Parser::new("\tx".chars());
+    ///
+    /// it.next();
+    ///
+    /// assert_eq!(it.col(), 1);
+    /// ```
+    pub fn col(&self) -> usize {
+        self.core.state.pos.col
+    }
+
+    /// Returns the current position.
+    ///
+    /// ```rust
+    /// # use peeking_iter::{Parser, Position};
+    /// let mut it = Parser::new("ab\nc".chars());
+    ///
+    /// let start = it.position();
+    ///
+    /// it.next();
+    /// it.next();
+    /// it.next();
+    ///
+    /// assert_eq!(it.position(), Position { line: 2, col: 0, offset: 3 });
+    /// assert!(start < it.position());
+    /// ```
+    pub fn position(&self) -> Position {
+        self.core.state.pos
+    }
+
+    /// Returns the position of the peeking iterator, i.e. right after the
+    /// last peeked character.
+    ///
+    /// ```rust
+    /// # use peeking_iter::{Parser, Position};
+    /// let mut it = Parser::new("a\nbc".chars());
+    ///
+    /// it.peek();
+    /// it.peek();
+    /// it.peek();
+    ///
+    /// assert_eq!(it.peek_position(), Position { line: 2, col: 1, offset: 3 });
+    /// assert_eq!(it.position(), Position::default());
+    ///
+    /// it.rewind_peeking();
+    ///
+    /// assert_eq!(it.peek_position(), it.position());
+    /// ```
+    pub fn peek_position(&self) -> Position {
+        self.core.peek_state().pos
+    }
+
+    /// Saves the current state, including the position.
+    ///
+    /// Use [`rollback()`](Self::rollback()) to restore it.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("a\nb".chars());
+    ///
+    /// it.next();
+    ///
+    /// let checkpoint = it.checkpoint();
+    ///
+    /// it.next();
+    /// it.next();
+    /// assert_eq!(it.line(), 2);
+    ///
+    /// it.rollback(checkpoint);
+    ///
+    /// assert_eq!((it.line(), it.col(), it.offset()), (1, 1, 1));
+    /// assert_eq!(it.next(), Some('\n'));
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint<I> {
+        let (iter, location) = self.core.save();
+
+        Checkpoint { iter, location }
+    }
+
+    /// Restores a state saved by [`checkpoint()`](Self::checkpoint()).
+    ///
+    /// Resets the peeking iterator.
+    pub fn rollback(&mut self, checkpoint: Checkpoint<I>) {
+        self.core.restore((checkpoint.iter, checkpoint.location));
+    }
+
+    /// Runs `f` against `self`, keeping everything it consumed only if it
+    /// succeeds (returns `Some`/`Ok`).
+    ///
+    /// Otherwise the parser, including its position, is restored to where
+    /// it was before the call.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("let x".chars());
+    ///
+    /// let res = it.attempt(|p| p.expect_str("let").and_then(|_| p.expect('(')));
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(it.col(), 0);
+    ///
+    /// let res = it.attempt(|p| p.expect_str("let").and_then(|_| p.expect(' ')));
+    ///
+    /// assert_eq!(res, Ok(' '));
+    /// assert_eq!(it.col(), 4);
+    /// ```
+    pub fn attempt<R: Outcome, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let checkpoint = self.checkpoint();
+        let result = f(self);
+
+        if !result.is_success() {
+            self.rollback(checkpoint);
+        }
+
+        result
+    }
+
+    /// Parses a `T` at the current position.
+    ///
+    /// On error, the parser is left untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("42 x".chars());
+    ///
+    /// assert_eq!(it.parse::<u16>(), Ok(42));
+    /// it.next();
+    /// assert!(it.parse::<i32>().is_err());
+    /// assert_eq!(it.next(), Some('x'));
+    /// ```
+    pub fn parse<T: FromParser>(&mut self) -> Result<T, ParseError> {
+        self.attempt(T::parse)
+    }
+
+    /// Returns the span from `start` to the current position.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("ab c".chars());
+    ///
+    /// let start = it.position();
+    /// it.next();
+    /// it.next();
+    /// let span = it.span_since(start);
+    ///
+    /// assert_eq!(span.start.col, 0);
+    /// assert_eq!(span.end.col, 2);
+    /// assert_eq!(span.len(), 2);
+    /// ```
+    pub fn span_since(&self, start: Position) -> Span {
+        Span::new(start, self.core.state.pos)
+    }
+
+    /// Returns the number of bytes consumed so far.
+    ///
+    /// Can be used to slice back into the original `&str`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let src = "añb";
+    /// let mut it = Parser::new(src.chars());
+    ///
+    /// it.next();
+    /// it.next();
+    ///
+    /// assert_eq!(it.offset(), 3);
+    /// assert_eq!(&src[it.offset()..], "b");
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.core.state.pos.offset
+    }
+
+    /// Returns the number of characters consumed so far.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("añb".chars());
+    ///
+    /// it.next();
+    /// it.next();
+    ///
+    /// assert_eq!(it.char_offset(), 2);
+    /// ```
+    pub fn char_offset(&self) -> usize {
+        self.core.state.char_offset
+    }
+
+    /// Returns whether all the input has been consumed.
+    ///
+    /// Unlike checking [`peek()`](Self::peek()), this doesn't move the
+    /// peeking iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::new("a".chars());
+    ///
+    /// assert_eq!(it.peek(), Some('a'));
+    /// assert!(!it.at_end());
+    /// assert_eq!(it.peek(), None);
+    ///
+    /// it.next();
+    /// assert!(it.at_end());
+    /// ```
+    pub fn at_end(&self) -> bool {
+        self.core.iter.clone().next().is_none()
+    }
+
+    /// Consumes the next character if it matches `pred`, or returns an
+    /// error with `expected`.
+    fn next_matching<F: FnOnce(char) -> bool>(
+        &mut self,
+        pred: F,
+        expected: Expected,
+    ) -> Result<char, ParseError> {
+        self.rewind_peeking();
+
+        match self.peek() {
+            Some(c) if pred(c) => {
+                self.next();
+                Ok(c)
+            }
+            found => {
+                self.rewind_peeking();
+                Err(self.error(found, expected))
+            }
+        }
+    }
+
+    /// Consumes all continuous characters that `pred` returns `true` for.
+    ///
+    /// Returns the number of characters consumed.
+    fn skip_while<F: Fn(char) -> bool>(&mut self, pred: F) -> usize {
+        let mut skipped = 0;
+
+        // If `peeking` had already diverged, bring it back
+        self.rewind_peeking();
+
+        while self.peek().is_some_and(&pred) {
+            self.next();
+            skipped += 1;
+        }
+
+        self.rewind_peeking();
+
+        skipped
+    }
+
+    /// Creates an error at the current position.
+    fn error(&self, found: Option<char>, expected: Expected) -> ParseError {
+        self.error_at(self.core.state.pos, found, expected)
+    }
+
+    /// Creates an error for a number that doesn't fit in its type, at the
+    /// current position.
+    fn overflow_error(&self) -> ParseError {
+        let found = self.core.iter.clone().next();
+
+        ParseError {
+            kind: ErrorKind::Overflow,
+            ..self.error(found, Expected::Description("integer in range"))
+        }
+    }
+
+    /// Creates an error for an invalid escape sequence at `position`.
+    #[cfg(feature = "alloc")]
+    fn escape_error(&self, position: Position, found: Option<char>) -> ParseError {
+        ParseError {
+            kind: ErrorKind::InvalidEscape,
+            ..self.error_at(position, found, Expected::Description("escape sequence"))
+        }
+    }
+
+    /// Returns the length of the line ending the input continues with, or 0
+    /// if it doesn't start with one.
+    fn line_ending_len(&self) -> usize {
+        let (lf, crlf, cr) = self.core.tracker.recognized();
+        let mut ahead = self.core.iter.clone();
+
+        match ahead.next() {
+            Some('\r') if crlf && ahead.next() == Some('\n') => 2,
+            Some('\r') if cr => 1,
+            Some('\n') if lf => 1,
+            _ => 0,
+        }
+    }
+
+    /// Creates an error at `position`.
+    fn error_at(&self, position: Position, found: Option<char>, expected: Expected) -> ParseError {
+        ParseError {
+            #[cfg(feature = "alloc")]
+            source_name: self.source_name.clone(),
+            ..ParseError::new(position, found, expected)
+        }
+    }
+}
+
+/// Advances `pos` past `c`, with `next` returning the character right after
+/// it.
+pub(crate) fn step(
+    pos: &mut Position,
+    after_cr: &mut bool,
+    line_endings: LineEndings,
+    c: char,
+    next: impl FnOnce() -> Option<char>,
+) {
+    pos.offset += c.len_utf8();
+
+    let after_cr = core::mem::replace(after_cr, c == '\r');
+    let (lf, crlf, cr) = line_endings.recognized();
+
+    match c {
+        '\n' if lf || (crlf && after_cr) => {
+            pos.line += 1;
+            pos.col = 0;
+        }
+        // The `\r` of `\r\n` doesn't take up a column
+        '\r' if crlf && next() == Some('\n') => (),
+        '\r' if cr => {
+            pos.line += 1;
+            pos.col = 0;
+        }
+        _ => pos.col += char_width(c),
+    }
+}
+
+/// Returns the number of columns `c` takes up.
+///
+/// Unless `unicode-width` is enabled, this is 1 for every character. With
+/// it, it's the display width, except for tabs and other control characters,
+/// which still count as 1.
+#[cfg(feature = "unicode-width")]
+pub(crate) fn char_width(c: char) -> usize {
+    match c {
+        '\t' => 1,
+        _ => unicode_width::UnicodeWidthChar::width(c).unwrap_or(1),
+    }
+}
+
+#[cfg(not(feature = "unicode-width"))]
+pub(crate) fn char_width(_: char) -> usize {
+    1
+}
+
+impl<I: ExactSizeIterator<Item = char> + Clone> Parser<I> {
+    /// Returns the number of characters left to consume.
+    ///
+    /// For a [`StrParser`], see
+    /// [`remaining_chars()`](Parser::remaining_chars()).
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let chars = ['a', 'b', 'c'];
+    /// let mut it = Parser::new(chars.into_iter());
+    ///
+    /// it.next();
+    /// it.peek();
+    ///
+    /// assert_eq!(it.remaining_len(), 2);
+    /// ```
+    pub fn remaining_len(&self) -> usize {
+        self.core.iter.len()
+    }
+}
+
+/// A [`Parser`] over a `&str`.
+///
+/// Created by [`Parser::from_str()`].
+pub type StrParser<'a> = Parser<Chars<'a>>;
+
+impl<'a> Parser<Chars<'a>> {
+    /// Wraps the characters of `s`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Parser, StrParser};
+    /// let mut it: StrParser = Parser::from_str("ab");
+    ///
+    /// assert_eq!(it.next(), Some('a'));
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Self {
+        Self::new(s.chars())
+    }
+
+    /// Returns the remaining input.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::from_str("abc");
+    ///
+    /// it.next();
+    ///
+    /// assert_eq!(it.as_str(), "bc");
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        self.core.iter.as_str()
+    }
+
+    /// Returns the number of characters left to consume.
+    ///
+    /// **NOTE:** This counts the characters of [`as_str()`](Self::as_str()),
+    /// so it's linear in their number.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::from_str("añb");
+    ///
+    /// it.next();
+    ///
+    /// assert_eq!(it.remaining_chars(), 2);
+    /// ```
+    pub fn remaining_chars(&self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    /// Like [`next_while()`](Self::next_while()), but returns a slice of
+    /// the input instead of allocating a `String`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let src = String::from("abc123");
+    /// let mut it = Parser::from_str(&src);
+    ///
+    /// let word: &str = it.next_while_str(|c| c.is_alphabetic());
+    ///
+    /// assert_eq!(word, "abc");
+    /// assert_eq!(it.next(), Some('1'));
+    /// ```
+    pub fn next_while_str<F: Fn(char) -> bool>(&mut self, pred: F) -> &'a str {
+        let rest = self.as_str();
+        let start = self.core.state.pos.offset;
+
+        self.skip_while(pred);
+
+        &rest[..self.core.state.pos.offset - start]
+    }
+
+    /// Like [`take_until()`](Self::take_until()), but returns a slice of the
+    /// input instead of allocating a `String`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::{parser::Terminator, Parser};
+    /// let mut it = Parser::from_str("key=value");
+    ///
+    /// assert_eq!(it.take_until_str('=', Terminator::Skip), ("key", true));
+    /// assert_eq!(it.take_until_str(';', Terminator::Skip), ("value", false));
+    /// ```
+    pub fn take_until_str<P: CharPattern>(
+        &mut self,
+        pattern: P,
+        terminator: Terminator,
+    ) -> (&'a str, bool) {
+        let rest = self.as_str();
+        let start = self.core.state.pos.offset;
+
+        self.skip_while(|c| !pattern.matches(c));
+
+        let found = self.core.iter.clone().next().is_some();
+        let mut end = self.core.state.pos.offset - start;
+
+        if found {
+            match terminator {
+                Terminator::Keep => (),
+                Terminator::Include => {
+                    self.next();
+                    end = self.core.state.pos.offset - start;
+                }
+                Terminator::Skip => {
+                    self.next();
+                }
+            }
+        }
+
+        (&rest[..end], found)
+    }
+
+    /// Like [`take_line()`](Self::take_line()), but returns a slice of the
+    /// input instead of allocating a `String`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::{parser::Terminator, Parser};
+    /// let mut it = Parser::from_str("one\ntwo");
+    ///
+    /// assert_eq!(it.take_line_str(Terminator::Include), "one\n");
+    /// assert_eq!(it.take_line_str(Terminator::Skip), "two");
+    /// ```
+    pub fn take_line_str(&mut self, terminator: Terminator) -> &'a str {
+        let rest = self.as_str();
+        let mut len = 0;
+
+        self.scan_line(terminator, |c| len += c.len_utf8());
+
+        &rest[..len]
+    }
+
+    /// Like [`current_line()`](Self::current_line()), but returns a slice of
+    /// the input instead of allocating a `String`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::from_str("x\r\nyz");
+    ///
+    /// it.next_while_str(|c| c != 'z');
+    ///
+    /// assert_eq!(it.current_line_str(), "yz");
+    /// ```
+    pub fn current_line_str(&self) -> &'a str {
+        Parser::from_str(self.core.state.line_start.as_str())
+            .line_endings(self.core.tracker)
+            .take_line_str(Terminator::Keep)
+    }
+
+    /// Like [`lines()`](Self::lines()), but yields slices of the input
+    /// instead of allocating `String`s.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let mut it = Parser::from_str("x\ny");
+    ///
+    /// let lines: Vec<_> = it.lines_str().map(|(pos, line)| (pos.line, line)).collect();
+    ///
+    /// assert_eq!(lines, [(1, "x"), (2, "y")]);
+    /// ```
+    pub fn lines_str(&mut self) -> LinesStr<'_, 'a> {
+        LinesStr::new(self)
+    }
+}
+
+impl<'a> From<&'a str> for Parser<Chars<'a>> {
+    fn from(value: &'a str) -> Self {
+        Self::from_str(value)
     }
 }
 
@@ -0,0 +1,112 @@
+use crate::Position;
+
+/// Keeps track of where an iterator is, for [`PeekCore`].
+pub(crate) trait Track<I: Iterator, S> {
+    /// Updates `state` after `item` was consumed, with `rest` being the
+    /// iterator right after it.
+    fn bump(&mut self, state: &mut S, item: &I::Item, rest: &I);
+}
+
+impl<I: Iterator, F: FnMut(&mut Position, &I::Item)> Track<I, Position> for F {
+    fn bump(&mut self, pos: &mut Position, item: &I::Item, _: &I) {
+        self(pos, item)
+    }
+}
+
+/// The peeking machinery shared by [`Parser`](crate::Parser) and
+/// [`TokenParser`](crate::parser::TokenParser): a base iterator and a lazily
+/// cloned peeking one, each with its own tracked state.
+pub(crate) struct PeekCore<I, K, S> {
+    pub(crate) iter: I,
+    /// The peeking iterator and its state, if it diverged from the base.
+    peeking: Option<(I, S)>,
+    peek_offset: usize,
+    pub(crate) tracker: K,
+    pub(crate) state: S,
+}
+
+impl<I: Iterator + Clone, K: Track<I, S>, S: Clone> PeekCore<I, K, S> {
+    pub(crate) fn new(iter: I, tracker: K, state: S) -> Self {
+        Self {
+            iter,
+            peeking: None,
+            peek_offset: 0,
+            tracker,
+            state,
+        }
+    }
+
+    /// Returns the next item of the base iterator, resetting the peeking one.
+    pub(crate) fn next(&mut self) -> Option<I::Item> {
+        self.rewind_peeking();
+
+        let next = self.iter.next();
+
+        if let Some(item) = &next {
+            self.tracker.bump(&mut self.state, item, &self.iter);
+        }
+
+        next
+    }
+
+    /// Returns the next item of the peeking iterator.
+    pub(crate) fn peek(&mut self) -> Option<I::Item> {
+        let (peeking, state) = self
+            .peeking
+            .get_or_insert_with(|| (self.iter.clone(), self.state.clone()));
+        let next = peeking.next();
+
+        if let Some(item) = &next {
+            self.peek_offset += 1;
+            self.tracker.bump(state, item, peeking);
+        }
+
+        next
+    }
+
+    /// Peeks `n` items past the peeking iterator and returns the next one,
+    /// or `None` if the input ends before that.
+    pub(crate) fn peek_nth(&mut self, n: usize) -> Option<I::Item> {
+        for _ in 0..n {
+            self.peek()?;
+        }
+
+        self.peek()
+    }
+
+    /// Moves the base iterator and its state to the peeking one.
+    pub(crate) fn advance_to_peeked(&mut self) {
+        if let Some((iter, state)) = self.peeking.take() {
+            self.iter = iter;
+            self.state = state;
+        }
+
+        self.peek_offset = 0;
+    }
+
+    pub(crate) fn rewind_peeking(&mut self) {
+        self.peeking = None;
+        self.peek_offset = 0;
+    }
+
+    /// Returns the state at the peeking iterator.
+    pub(crate) fn peek_state(&self) -> &S {
+        self.peeking
+            .as_ref()
+            .map_or(&self.state, |(_, state)| state)
+    }
+
+    /// Saves the base iterator and its state.
+    pub(crate) fn save(&self) -> (I, S) {
+        (self.iter.clone(), self.state.clone())
+    }
+
+    /// Restores what was saved by [`save()`](Self::save()), resetting the
+    /// peeking iterator.
+    pub(crate) fn restore(&mut self, (iter, state): (I, S)) {
+        self.iter = iter;
+        self.state = state;
+
+        self.rewind_peeking();
+    }
+}
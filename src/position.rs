@@ -0,0 +1,90 @@
+use core::ops::Range;
+
+/// A position in [`Parser`](crate::Parser)'s input.
+///
+/// Returned by [`Parser::position()`](crate::Parser::position()).
+///
+/// **NOTE:** By convention, line numbers start at 1, while column numbers
+/// start at 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    /// Line number.
+    pub line: usize,
+    /// Column number.
+    pub col: usize,
+    /// Number of bytes preceding the position.
+    pub offset: usize,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self {
+            line: 1,
+            col: 0,
+            offset: 0,
+        }
+    }
+}
+
+/// A range between two [`Position`]s.
+///
+/// Returned by [`Parser::span_since()`](crate::Parser::span_since()).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// Start position (inclusive).
+    pub start: Position,
+    /// End position (exclusive).
+    pub end: Position,
+}
+
+impl Span {
+    /// Creates a span between `start` and `end`.
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the byte range of the span.
+    ///
+    /// Can be used to slice back into the original `&str`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let src = "let x";
+    /// let mut it = Parser::new(src.chars());
+    ///
+    /// let start = it.position();
+    /// it.next_while(|c| c.is_alphabetic());
+    ///
+    /// assert_eq!(&src[it.span_since(start).range()], "let");
+    /// ```
+    pub fn range(&self) -> Range<usize> {
+        self.start.offset..self.end.offset
+    }
+
+    /// Returns the length of the span in bytes.
+    pub fn len(&self) -> usize {
+        self.end.offset - self.start.offset
+    }
+
+    /// Returns `true` if the span is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the smallest span containing both `self` and `other`.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+}
+
+impl From<Span> for Range<usize> {
+    /// Returns the byte range of the span, as in [`Span::range()`].
+    fn from(value: Span) -> Self {
+        value.range()
+    }
+}
@@ -0,0 +1,59 @@
+use std::{eprintln, format};
+
+/// What to do when [`repeat_peek_protection()`] detects too many rescans.
+///
+/// [`repeat_peek_protection()`]: crate::PeekingIter::repeat_peek_protection()
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepeatPeekAction {
+    /// Print a warning to stderr.
+    Warn,
+    /// Panic (useful in tests).
+    Panic,
+}
+
+/// Counts how many times the same lookahead region is re-scanned without
+/// the base iterator advancing.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RepeatPeekProtection {
+    limit: Option<(usize, RepeatPeekAction)>,
+    scanned: bool,
+    rescans: usize,
+}
+
+impl RepeatPeekProtection {
+    pub(crate) fn set(&mut self, limit: usize, action: RepeatPeekAction) {
+        self.limit = Some((limit, action));
+    }
+
+    /// Called whenever a new peek sequence starts at the base iterator.
+    pub(crate) fn on_scan(&mut self) {
+        let Some((limit, action)) = self.limit else {
+            return;
+        };
+
+        if !self.scanned {
+            self.scanned = true;
+            return;
+        }
+
+        self.rescans += 1;
+
+        if self.rescans > limit {
+            let msg = format!(
+                "peeking-iter: lookahead re-scanned {} times without advancing (limit: {limit})",
+                self.rescans
+            );
+
+            match action {
+                RepeatPeekAction::Warn => eprintln!("{msg}"),
+                RepeatPeekAction::Panic => panic!("{msg}"),
+            }
+        }
+    }
+
+    /// Called whenever the base iterator advances.
+    pub(crate) fn on_advance(&mut self) {
+        self.scanned = false;
+        self.rescans = 0;
+    }
+}
@@ -0,0 +1,259 @@
+use crate::Parser;
+use alloc::{collections::BTreeMap, collections::VecDeque, rc::Rc};
+use core::cell::RefCell;
+use std::io::{self, Read};
+
+/// Size of the chunks read from the reader.
+pub(crate) const CHUNK: usize = 8 * 1024;
+
+/// Incremental UTF-8 decoder, replacing invalid sequences with `U+FFFD`.
+#[derive(Default)]
+pub(crate) struct Utf8Decoder {
+    /// Undecoded bytes, possibly ending with an incomplete sequence.
+    bytes: VecDeque<u8>,
+}
+
+impl Utf8Decoder {
+    /// Decodes `input` (following previously passed bytes) into `out`.
+    ///
+    /// An incomplete sequence at the end is kept for the next call, unless
+    /// `eof`.
+    pub(crate) fn decode(&mut self, input: &[u8], eof: bool, out: &mut VecDeque<char>) {
+        self.bytes.extend(input);
+
+        let bytes = self.bytes.make_contiguous();
+        let mut valid = 0;
+
+        loop {
+            match core::str::from_utf8(&bytes[valid..]) {
+                Ok(s) => {
+                    out.extend(s.chars());
+                    valid = bytes.len();
+                    break;
+                }
+                Err(e) => {
+                    let end = valid + e.valid_up_to();
+
+                    // `valid_up_to()` is on a char boundary
+                    let s = core::str::from_utf8(&bytes[valid..end]).unwrap_or_default();
+                    out.extend(s.chars());
+
+                    match e.error_len() {
+                        Some(len) => {
+                            out.push_back(char::REPLACEMENT_CHARACTER);
+                            valid = end + len;
+                        }
+                        // An incomplete sequence at the end
+                        None if !eof => {
+                            valid = end;
+                            break;
+                        }
+                        None => {
+                            out.push_back(char::REPLACEMENT_CHARACTER);
+                            valid = bytes.len();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        self.bytes.drain(..valid);
+    }
+}
+
+/// A cheaply cloneable iterator over the characters of a [`Read`],
+/// decoded as UTF-8 (invalid sequences become `U+FFFD`).
+///
+/// Created by [`Parser::from_reader()`]. All clones share one buffer, which
+/// only retains the characters between the furthest-behind and the
+/// furthest-ahead clone, so memory stays bounded.
+///
+/// ```rust
+/// # use peeking_iter::parser::ReadChars;
+/// use std::io::Read;
+///
+/// // Yields one byte per read
+/// struct Trickle<'a>(&'a [u8]);
+///
+/// impl Read for Trickle<'_> {
+///     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+///         let n = self.0.len().min(buf.len()).min(1);
+///
+///         buf[..n].copy_from_slice(&self.0[..n]);
+///         self.0 = &self.0[n..];
+///
+///         Ok(n)
+///     }
+/// }
+///
+/// let chars = ReadChars::new(Trickle("añ€".as_bytes()));
+///
+/// assert_eq!(chars.clone().collect::<String>(), "añ€");
+/// assert_eq!(chars.count(), 3);
+/// ```
+pub struct ReadChars<R> {
+    shared: Rc<RefCell<Shared<R>>>,
+    index: usize,
+}
+
+struct Shared<R> {
+    reader: R,
+    decoder: Utf8Decoder,
+    chars: VecDeque<char>,
+    /// Index of `chars[0]` in the whole input.
+    start: usize,
+    /// Number of live clones at each index.
+    cursors: BTreeMap<usize, usize>,
+    eof: bool,
+    error: Option<io::Error>,
+}
+
+impl<R: Read> ReadChars<R> {
+    /// Wraps the given reader.
+    pub fn new(reader: R) -> Self {
+        let shared = Shared {
+            reader,
+            decoder: Utf8Decoder::default(),
+            chars: VecDeque::new(),
+            start: 0,
+            cursors: BTreeMap::from([(0, 1)]),
+            eof: false,
+            error: None,
+        };
+
+        Self {
+            shared: Rc::new(RefCell::new(shared)),
+            index: 0,
+        }
+    }
+
+    /// Takes the I/O error that ended the input, if any.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.shared.borrow_mut().error.take()
+    }
+}
+
+impl<R: Read> Shared<R> {
+    /// Returns the character at `index`, reading more input if needed.
+    fn get(&mut self, index: usize) -> Option<char> {
+        while index - self.start >= self.chars.len() {
+            if self.eof {
+                return None;
+            }
+
+            self.fill();
+        }
+
+        self.chars.get(index - self.start).copied()
+    }
+
+    /// Reads and decodes another chunk, dropping the characters no clone
+    /// can reach anymore.
+    fn fill(&mut self) {
+        let oldest = self.cursors.keys().next().copied().unwrap_or(self.start);
+        let unreachable = oldest.saturating_sub(self.start).min(self.chars.len());
+
+        self.chars.drain(..unreachable);
+        self.start += unreachable;
+
+        let mut buf = [0; CHUNK];
+
+        let n = match self.reader.read(&mut buf) {
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => return,
+            Err(e) => {
+                self.error = Some(e);
+                0
+            }
+        };
+
+        self.eof = n == 0;
+        self.decoder.decode(&buf[..n], self.eof, &mut self.chars);
+    }
+}
+
+impl<R> Shared<R> {
+    fn add_cursor(&mut self, index: usize) {
+        *self.cursors.entry(index).or_default() += 1;
+    }
+
+    fn remove_cursor(&mut self, index: usize) {
+        if let Some(count) = self.cursors.get_mut(&index) {
+            *count -= 1;
+
+            if *count == 0 {
+                self.cursors.remove(&index);
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for ReadChars<R> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let mut shared = self.shared.borrow_mut();
+        let next = shared.get(self.index);
+
+        if next.is_some() {
+            shared.remove_cursor(self.index);
+            shared.add_cursor(self.index + 1);
+            self.index += 1;
+        }
+
+        next
+    }
+}
+
+impl<R> Clone for ReadChars<R> {
+    fn clone(&self) -> Self {
+        self.shared.borrow_mut().add_cursor(self.index);
+
+        Self {
+            shared: Rc::clone(&self.shared),
+            index: self.index,
+        }
+    }
+}
+
+impl<R> Drop for ReadChars<R> {
+    fn drop(&mut self) {
+        if let Ok(mut shared) = self.shared.try_borrow_mut() {
+            shared.remove_cursor(self.index);
+        }
+    }
+}
+
+impl<R: Read> Parser<ReadChars<R>> {
+    /// Wraps the given reader, decoding it as UTF-8 while parsing.
+    ///
+    /// Only the input from the start of the current line (or the oldest
+    /// [`checkpoint()`](Self::checkpoint())) up to the peeking iterator is
+    /// kept in memory.
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// let input = "key = 42\nnext".as_bytes();
+    /// let mut it = Parser::from_reader(input);
+    ///
+    /// assert_eq!(it.next_while(|c| c.is_alphabetic()), "key");
+    /// it.skip_whitespace();
+    /// it.expect('=').unwrap();
+    /// it.skip_whitespace();
+    ///
+    /// assert_eq!(it.parse_integer::<u32>(10), Ok(42));
+    /// assert!(it.take_io_error().is_none());
+    /// ```
+    pub fn from_reader(reader: R) -> Self {
+        Self::new(ReadChars::new(reader))
+    }
+
+    /// Takes the I/O error that ended the input, if any.
+    ///
+    /// A reader error looks like the end of input to the parser, so this
+    /// should be checked when parsing fails.
+    pub fn take_io_error(&self) -> Option<io::Error> {
+        self.inner().take_error()
+    }
+}
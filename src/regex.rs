@@ -0,0 +1,105 @@
+use crate::{Parser, Span};
+use alloc::string::String;
+use regex_automata::{
+    dfa::{Automaton, StartError},
+    util::start,
+    Anchored,
+};
+
+impl<I: Iterator<Item = char> + Clone> Parser<I> {
+    /// Matches the regex compiled into `dfa` at the current position,
+    /// consuming and returning the match with its span.
+    ///
+    /// The search is anchored at the current position, and the input is fed
+    /// to the DFA one character at a time, so only the match (and whatever it
+    /// takes to rule out a longer one) is ever peeked. Returns `None` and
+    /// leaves the parser untouched if there's no match.
+    ///
+    /// `^` and other look-behind assertions see the current position as the
+    /// start of the input. If the DFA gives up on a quit byte, there's no
+    /// match.
+    ///
+    /// # Panics
+    ///
+    /// If `dfa` wasn't built to support anchored searches (the default
+    /// [`StartKind::Both`](regex_automata::dfa::StartKind) does).
+    ///
+    /// ```rust
+    /// # use peeking_iter::Parser;
+    /// use regex_automata::dfa::dense::DFA;
+    ///
+    /// let version = DFA::new(r"\d+\.\d+\.\d+").unwrap();
+    /// let mut it = Parser::new("v1.10.2 ok".chars());
+    ///
+    /// assert_eq!(it.match_regex(&version), None);
+    /// it.next();
+    ///
+    /// let (s, span) = it.match_regex(&version).unwrap();
+    ///
+    /// assert_eq!(s, "1.10.2");
+    /// assert_eq!(span.range(), 1..7);
+    /// assert_eq!(it.next(), Some(' '));
+    ///
+    /// // Only the lookahead needed for the match is read
+    /// let mut it = Parser::new("ab".chars().cycle());
+    ///
+    /// assert_eq!(it.match_regex(&DFA::new("abb").unwrap()), None);
+    /// assert_eq!(it.match_regex(&DFA::new("ab|abab").unwrap()).unwrap().0, "ab");
+    /// ```
+    pub fn match_regex<A: Automaton>(&mut self, dfa: &A) -> Option<(String, Span)> {
+        self.rewind_peeking();
+
+        let config = start::Config::new().anchored(Anchored::Yes);
+        let mut state = match dfa.start_state(&config) {
+            Ok(state) => state,
+            Err(StartError::UnsupportedAnchored { .. }) => {
+                panic!("the DFA doesn't support anchored searches")
+            }
+            Err(_) => return None,
+        };
+        let mut len = None;
+        let mut peeked = 0;
+        let mut buf = [0; 4];
+
+        while let Some(c) = self.peek() {
+            for (i, &byte) in c.encode_utf8(&mut buf).as_bytes().iter().enumerate() {
+                state = dfa.next_state(state, byte);
+
+                if !dfa.is_special_state(state) {
+                    continue;
+                }
+
+                // Match states are delayed by one byte, so a match seen right
+                // after the first byte of a character ends before it
+                if dfa.is_match_state(state) {
+                    if i == 0 {
+                        len = Some(peeked);
+                    }
+                } else if dfa.is_dead_state(state) {
+                    return self.consume_match(len);
+                } else if dfa.is_quit_state(state) {
+                    return self.consume_match(None);
+                }
+            }
+
+            peeked += 1;
+        }
+
+        if dfa.is_match_state(dfa.next_eoi_state(state)) {
+            len = Some(peeked);
+        }
+
+        self.consume_match(len)
+    }
+
+    /// Consumes `len` characters, if any.
+    fn consume_match(&mut self, len: Option<usize>) -> Option<(String, Span)> {
+        self.rewind_peeking();
+
+        let len = len?;
+        let start = Self::position(self);
+        let s = (0..len).filter_map(|_| self.next()).collect();
+
+        Some((s, self.span_since(start)))
+    }
+}
@@ -0,0 +1,36 @@
+use crate::PeekingIter;
+use alloc::vec::Vec;
+
+/// Iterator over runs of consecutive items sharing a key.
+///
+/// Created by [`PeekingIter::runs_by_key()`].
+pub struct RunsByKey<I: Iterator, F> {
+    iter: PeekingIter<I>,
+    key: F,
+}
+
+impl<I: Iterator + Clone, F> RunsByKey<I, F> {
+    pub(crate) fn new(iter: PeekingIter<I>, key: F) -> Self {
+        Self { iter, key }
+    }
+
+    /// Consumes `self` and returns the wrapped [`PeekingIter`].
+    pub fn into_inner(value: Self) -> PeekingIter<I> {
+        value.iter
+    }
+}
+
+impl<I, K, F> Iterator for RunsByKey<I, F>
+where
+    I: Iterator + Clone,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let run = self.iter.next_run_by_key(&self.key);
+
+        (!run.is_empty()).then_some(run)
+    }
+}
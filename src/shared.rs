@@ -0,0 +1,108 @@
+use crate::{Outcome, PeekingIter};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Thread-safe handle to a [`PeekingIter`], so that several threads can
+/// cooperatively consume one source.
+///
+/// Cloning the handle doesn't clone the iterator. The peeking state is
+/// shared too, so multi-step operations should be done atomically, using
+/// [`speculate()`](Self::speculate()) or [`lock()`](Self::lock()).
+///
+/// ```rust
+/// # use peeking_iter::SharedPeekingIter;
+/// let it = SharedPeekingIter::new(0..100);
+///
+/// let sum: u32 = std::thread::scope(|s| {
+///     let workers: Vec<_> = (0..4)
+///         .map(|_| {
+///             let it = it.clone();
+///
+///             s.spawn(move || it.sum::<u32>())
+///         })
+///         .collect();
+///
+///     workers.into_iter().map(|w| w.join().unwrap()).sum()
+/// });
+///
+/// assert_eq!(sum, (0..100).sum());
+/// ```
+pub struct SharedPeekingIter<I: Iterator> {
+    inner: Arc<Mutex<PeekingIter<I>>>,
+}
+
+impl<I: Iterator + Clone> SharedPeekingIter<I> {
+    /// Wraps the given iterator.
+    pub fn new(iter: I) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(PeekingIter::new(iter))),
+        }
+    }
+
+    /// Locks the iterator for exclusive access.
+    pub fn lock(&self) -> MutexGuard<'_, PeekingIter<I>> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the next item in the inner iterator.
+    ///
+    /// Resets the peeking iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&self) -> Option<I::Item> {
+        self.lock().next()
+    }
+
+    /// Peeks the next item in the inner iterator.
+    ///
+    /// Subsequent calls (from any thread) return subsequent items.
+    pub fn peek(&self) -> Option<I::Item> {
+        self.lock().peek()
+    }
+
+    /// Peek the `n`th value in the iterator.
+    pub fn peek_nth(&self, n: usize) -> Option<I::Item> {
+        self.lock().peek_nth(n)
+    }
+
+    /// Advances the base iterator to the be aligned with the peeking one.
+    pub fn advance_to_peeked(&self) {
+        self.lock().advance_to_peeked()
+    }
+
+    /// Rewind the peeking iterator to align with the base one.
+    pub fn rewind_peeking(&self) {
+        self.lock().rewind_peeking()
+    }
+
+    /// Atomically runs [`PeekingIter::speculate()`]: no other thread can
+    /// access the iterator until `f` returns, and its consumption is only
+    /// committed if it succeeds.
+    ///
+    /// ```rust
+    /// # use peeking_iter::SharedPeekingIter;
+    /// let it = SharedPeekingIter::new(0..=3);
+    ///
+    /// // Take a batch of two items, but only if both are there
+    /// let batch = it.speculate(|it| Some((it.next()?, it.next()?)));
+    ///
+    /// assert_eq!(batch, Some((0, 1)));
+    /// ```
+    pub fn speculate<R: Outcome, F: FnOnce(&mut PeekingIter<I>) -> R>(&self, f: F) -> R {
+        self.lock().speculate(f)
+    }
+}
+
+impl<I: Iterator> Clone for SharedPeekingIter<I> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<I: Iterator + Clone> Iterator for SharedPeekingIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        SharedPeekingIter::next(self)
+    }
+}
@@ -0,0 +1,204 @@
+use crate::parser::{char_width, LineEndings, ParseError, Span};
+use core::{fmt, ops::Range};
+
+/// A rendering of the source line containing a [`Span`], with the span
+/// underlined by carets.
+///
+/// Created by [`Snippet::new()`] or [`ParseError::snippet()`]. Only the
+/// first line of a multi-line span is shown.
+///
+/// ```rust
+/// # use peeking_iter::parser::Parser;
+/// let src = "let x = 1;\nlet = 2;";
+/// let mut it = Parser::new(src.chars());
+///
+/// it.take_line(Default::default());
+/// it.next();
+/// it.expect_str("let").unwrap();
+/// it.skip_whitespace();
+///
+/// let err = it.parse_identifier().unwrap_err();
+///
+/// assert_eq!(
+///     err.snippet(src).to_string(),
+///     "2 | let = 2;\n  |     ^\n",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Snippet<'a> {
+    source: &'a str,
+    span: Span,
+    name: Option<&'a str>,
+    line_endings: LineEndings,
+}
+
+impl<'a> Snippet<'a> {
+    /// Creates a snippet of `span` in `source`.
+    ///
+    /// `source` must be the whole input the span refers to.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Parser, Snippet};
+    /// let src = "x = 10";
+    /// let mut it = Parser::new(src.chars());
+    ///
+    /// it.next_while(|c| !c.is_ascii_digit());
+    ///
+    /// let (_, span) = it.parse_number().unwrap();
+    ///
+    /// assert_eq!(Snippet::new(src, span).to_string(), "1 | x = 10\n  |     ^^\n");
+    /// ```
+    pub fn new(source: &'a str, span: Span) -> Self {
+        Self {
+            source,
+            span,
+            name: None,
+            line_endings: LineEndings::default(),
+        }
+    }
+
+    /// Adds a `--> name:line:col` header, pointing at the start of the span.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{Position, Snippet, Span};
+    /// let pos = Position { line: 1, col: 4, offset: 4 };
+    /// let snippet = Snippet::new("x = ?", Span::new(pos, pos)).with_name("a.txt");
+    ///
+    /// assert_eq!(snippet.to_string(), " --> a.txt:1:5\n1 | x = ?\n  |     ^\n");
+    /// ```
+    pub fn with_name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the line endings the source is split into lines by.
+    ///
+    /// Should match the ones set with
+    /// [`Parser::line_endings()`](crate::Parser::line_endings()). Defaults to
+    /// [`LineEndings::Any`].
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{LineEndings, Parser, Snippet};
+    /// let src = "a\rb = ?";
+    /// let mut it = Parser::new(src.chars()).line_endings(LineEndings::Lf);
+    ///
+    /// it.next_while(|c| c != '?');
+    ///
+    /// let span = it.span_since(it.position());
+    /// let snippet = Snippet::new(src, span).line_endings(LineEndings::Lf);
+    ///
+    /// assert_eq!(snippet.to_string(), "1 | a\rb = ?\n  |       ^\n");
+    ///
+    /// let mut it = Parser::new(src.chars()).line_endings(LineEndings::Cr);
+    ///
+    /// it.next_while(|c| c != '?');
+    ///
+    /// let span = it.span_since(it.position());
+    /// let snippet = Snippet::new(src, span).line_endings(LineEndings::Cr);
+    ///
+    /// assert_eq!(snippet.to_string(), "2 | b = ?\n  |     ^\n");
+    /// ```
+    pub fn line_endings(mut self, line_endings: LineEndings) -> Self {
+        self.line_endings = line_endings;
+        self
+    }
+
+    /// Returns the byte range of the line containing `at`, excluding its
+    /// ending.
+    fn line_range(&self, at: usize) -> Range<usize> {
+        let (lf, crlf, cr) = self.line_endings.recognized();
+        let mut start = 0;
+        let mut chars = self.source.char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            let len = match c {
+                '\r' if crlf && self.source[i + 1..].starts_with('\n') => 2,
+                '\r' if cr => 1,
+                '\n' if lf => 1,
+                _ => continue,
+            };
+
+            if i + len > at {
+                return start..i;
+            }
+
+            start = i + len;
+
+            if len == 2 {
+                chars.next();
+            }
+        }
+
+        start..self.source.len()
+    }
+}
+
+impl fmt::Display for Snippet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = self.span.start.offset.min(self.source.len());
+        let range = self.line_range(start);
+        let line_start = range.start;
+        let line = self.source[range].trim_end_matches('\r');
+
+        let number = self.span.start.line;
+        let gutter = number.checked_ilog10().unwrap_or(0) as usize + 1;
+
+        if let Some(name) = self.name {
+            let col = self.span.start.col + 1;
+
+            writeln!(f, "{:gutter$}--> {name}:{number}:{col}", "")?;
+        }
+
+        writeln!(f, "{number} | {line}")?;
+        write!(f, "{:gutter$} | ", "")?;
+
+        // Keep tabs, so that the carets line up with the source
+        for c in self.source[line_start..start].chars() {
+            match c {
+                '\t' => f.write_str("\t")?,
+                _ => write!(f, "{:1$}", "", char_width(c))?,
+            }
+        }
+
+        let end = self.span.end.offset.min(line_start + line.len()).max(start);
+        let underline = self.source[start..end]
+            .chars()
+            .map(char_width)
+            .sum::<usize>();
+
+        for _ in 0..underline.max(1) {
+            f.write_str("^")?;
+        }
+
+        writeln!(f)
+    }
+}
+
+impl ParseError {
+    /// Returns a [`Snippet`] pointing at the error in `source`.
+    ///
+    /// If the error has a source name, the snippet is headed by it.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::Parser;
+    /// let src = "\tx = ?";
+    /// let mut it = Parser::new(src.chars());
+    ///
+    /// it.next_while(|c| c != '?');
+    ///
+    /// let err = it.expect('1').unwrap_err();
+    ///
+    /// assert_eq!(err.position.col, 5);
+    /// assert_eq!(err.snippet(src).to_string(), "1 | \tx = ?\n  | \t    ^\n");
+    /// ```
+    pub fn snippet<'a>(&'a self, source: &'a str) -> Snippet<'a> {
+        let snippet = Snippet::new(source, Span::new(self.position, self.position));
+
+        #[cfg(feature = "alloc")]
+        if let Some(name) = &self.source_name {
+            return snippet.with_name(name);
+        }
+
+        snippet
+    }
+}
@@ -0,0 +1,62 @@
+use crate::PeekingIter;
+use alloc::vec::Vec;
+
+/// Iterator over segments separated by items matching a predicate.
+///
+/// Created by [`PeekingIter::split_when()`] and
+/// [`PeekingIter::split_when_inclusive()`].
+pub struct SplitWhen<I: Iterator, F> {
+    iter: PeekingIter<I>,
+    pred: F,
+    inclusive: bool,
+    finished: bool,
+}
+
+impl<I: Iterator + Clone, F> SplitWhen<I, F> {
+    pub(crate) fn new(iter: PeekingIter<I>, pred: F, inclusive: bool) -> Self {
+        Self {
+            iter,
+            pred,
+            inclusive,
+            finished: false,
+        }
+    }
+
+    /// Consumes `self` and returns the wrapped [`PeekingIter`].
+    pub fn into_inner(value: Self) -> PeekingIter<I> {
+        value.iter
+    }
+}
+
+impl<I, F> Iterator for SplitWhen<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.finished {
+            return None;
+        }
+
+        let mut segment = self.iter.next_while(|x| !(self.pred)(x));
+
+        match self.iter.next() {
+            Some(delim) => {
+                if self.inclusive {
+                    segment.push(delim);
+                }
+            }
+            None => {
+                self.finished = true;
+
+                if self.inclusive && segment.is_empty() {
+                    return None;
+                }
+            }
+        }
+
+        Some(segment)
+    }
+}
@@ -0,0 +1,16 @@
+/// Instrumentation counters of a [`PeekingIter`](crate::PeekingIter).
+///
+/// Returned by [`PeekingIter::stats()`](crate::PeekingIter::stats()).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PeekStats {
+    /// Number of times the base iterator was cloned.
+    pub clones: usize,
+    /// Number of peeks.
+    pub peeks: usize,
+    /// Number of times a peeking iterator was discarded without being
+    /// committed.
+    pub rewinds: usize,
+    /// Number of times the base iterator was advanced to a peeking
+    /// iterator or a cursor.
+    pub commits: usize,
+}
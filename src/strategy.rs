@@ -0,0 +1,106 @@
+#[cfg(feature = "alloc")]
+use crate::BufferedPeekingIter;
+use crate::PeekingIter;
+
+/// The common peeking API, implemented by both the clone-based
+/// [`PeekingIter`] and the buffer-based [`BufferedPeekingIter`] and
+/// [`PeekingIterN`](crate::PeekingIterN).
+///
+/// Lets code be generic over the peeking strategy, so it can be chosen by
+/// the caller: cloning is cheap for ranges and slices, while buffering
+/// suits expensive-to-clone or side-effecting iterators.
+///
+/// ```rust
+/// # use peeking_iter::{BufferedPeekingIter, PeekStrategy, PeekingIter};
+/// fn arrow<P: PeekStrategy<Item = char>>(it: &mut P) -> bool {
+///     it.rewind_peeking();
+///
+///     if it.peek() == Some('-') && it.peek() == Some('>') {
+///         it.advance_to_peeked();
+///
+///         return true;
+///     }
+///
+///     false
+/// }
+///
+/// assert!(arrow(&mut PeekingIter::new("->".chars())));
+/// assert!(arrow(&mut BufferedPeekingIter::new("->".chars())));
+/// ```
+///
+/// [`BufferedPeekingIter`]: crate::BufferedPeekingIter
+pub trait PeekStrategy: Iterator {
+    /// Peeks the next item. Subsequent calls return subsequent items.
+    fn peek(&mut self) -> Option<Self::Item>;
+
+    /// Peeks `n` items past the peek cursor and returns the next one, or
+    /// `None` if fewer items are left.
+    ///
+    /// All strategies agree on short input:
+    ///
+    /// ```rust
+    /// # use peeking_iter::{BufferedPeekingIter, PeekStrategy, PeekingIter, PeekingIterN};
+    /// fn check<P: PeekStrategy<Item = u32>>(mut it: P) {
+    ///     assert_eq!(it.peek_nth(1), Some(1));
+    ///     assert_eq!(it.peek_nth(0), Some(2));
+    ///     assert_eq!(it.peek_nth(0), None);
+    ///
+    ///     it.rewind_peeking();
+    ///
+    ///     assert_eq!(it.peek_nth(5), None);
+    ///     assert_eq!(it.next(), Some(0));
+    /// }
+    ///
+    /// check(PeekingIter::new(0..=2));
+    /// check(BufferedPeekingIter::new(0..=2));
+    /// check(PeekingIterN::<_, 8>::new(0..=2));
+    /// ```
+    fn peek_nth(&mut self, n: usize) -> Option<Self::Item>;
+
+    /// Advances the base iterator to be aligned with the peek cursor.
+    fn advance_to_peeked(&mut self);
+
+    /// Rewinds the peek cursor to align with the base iterator.
+    fn rewind_peeking(&mut self);
+}
+
+impl<I: Iterator + Clone> PeekStrategy for PeekingIter<I> {
+    fn peek(&mut self) -> Option<I::Item> {
+        PeekingIter::peek(self)
+    }
+
+    fn peek_nth(&mut self, n: usize) -> Option<I::Item> {
+        PeekingIter::peek_nth(self, n)
+    }
+
+    fn advance_to_peeked(&mut self) {
+        PeekingIter::advance_to_peeked(self)
+    }
+
+    fn rewind_peeking(&mut self) {
+        PeekingIter::rewind_peeking(self)
+    }
+}
+
+/// Peeked items are cloned out of the buffer.
+#[cfg(feature = "alloc")]
+impl<I: Iterator> PeekStrategy for BufferedPeekingIter<I>
+where
+    I::Item: Clone,
+{
+    fn peek(&mut self) -> Option<I::Item> {
+        BufferedPeekingIter::peek(self).cloned()
+    }
+
+    fn peek_nth(&mut self, n: usize) -> Option<I::Item> {
+        BufferedPeekingIter::peek_nth(self, n).cloned()
+    }
+
+    fn advance_to_peeked(&mut self) {
+        BufferedPeekingIter::advance_to_peeked(self)
+    }
+
+    fn rewind_peeking(&mut self) {
+        BufferedPeekingIter::rewind_peeking(self)
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::{
+    future::poll_fn,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+/// Stream adapter that enables infinitely-deep peeking.
+///
+/// The async counterpart of [`PeekingIter`](crate::PeekingIter). Since
+/// streams can't be cloned, peeked items are kept in an internal buffer
+/// and returned by reference.
+///
+/// ```rust
+/// # use peeking_iter::PeekingStream;
+/// # futures::executor::block_on(async {
+/// let mut it = PeekingStream::new(futures::stream::iter(0..=2));
+///
+/// assert_eq!(it.next().await, Some(0));
+/// assert_eq!(it.peek().await, Some(&1));
+/// assert_eq!(it.peek().await, Some(&2));
+/// assert_eq!(it.next().await, Some(1));
+/// assert_eq!(it.peek().await, Some(&2));
+/// assert_eq!(it.peek().await, None);
+/// # });
+/// ```
+pub struct PeekingStream<S: Stream> {
+    stream: S,
+    buf: VecDeque<S::Item>,
+    peeked: usize,
+}
+
+impl<S: Stream + Unpin> PeekingStream<S> {
+    /// Wraps the given stream.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            buf: VecDeque::new(),
+            peeked: 0,
+        }
+    }
+
+    /// Pulls items from the inner stream until the buffer holds `n` items.
+    ///
+    /// Returns `false` if the stream ended before that.
+    async fn fill(&mut self, n: usize) -> bool {
+        while self.buf.len() < n {
+            match poll_fn(|cx| Pin::new(&mut self.stream).poll_next(cx)).await {
+                Some(x) => self.buf.push_back(x),
+                None => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Returns the next item in the inner stream.
+    ///
+    /// Resets the peeking cursor.
+    pub async fn next(&mut self) -> Option<S::Item> {
+        self.peeked = 0;
+
+        match self.buf.pop_front() {
+            Some(x) => Some(x),
+            None => poll_fn(|cx| Pin::new(&mut self.stream).poll_next(cx)).await,
+        }
+    }
+
+    /// Peeks the next item in the inner stream.
+    ///
+    /// Subsequent calls return subsequent items.
+    pub async fn peek(&mut self) -> Option<&S::Item> {
+        self.peek_nth(0).await
+    }
+
+    /// Peek the `n`th value in the stream.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingStream;
+    /// # futures::executor::block_on(async {
+    /// let mut it = PeekingStream::new(futures::stream::iter(0..=2));
+    ///
+    /// assert_eq!(it.peek_nth(2).await, Some(&2));
+    /// assert_eq!(it.next().await, Some(0));
+    /// # });
+    /// ```
+    pub async fn peek_nth(&mut self, n: usize) -> Option<&S::Item> {
+        let idx = self.peeked.checked_add(n)?;
+
+        if !self.fill(idx + 1).await {
+            self.peeked = self.buf.len();
+
+            return None;
+        }
+
+        self.peeked = idx + 1;
+
+        self.buf.get(idx)
+    }
+
+    /// Advances the base stream to the be aligned with the peeking cursor.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingStream;
+    /// # futures::executor::block_on(async {
+    /// let mut it = PeekingStream::new(futures::stream::iter(0..=2));
+    ///
+    /// it.peek().await;
+    /// it.peek().await;
+    /// it.advance_to_peeked();
+    ///
+    /// assert_eq!(it.next().await, Some(2));
+    /// # });
+    /// ```
+    pub fn advance_to_peeked(&mut self) {
+        self.buf.drain(..self.peeked);
+        self.peeked = 0;
+    }
+
+    /// Rewind the peeking cursor to align with the base stream.
+    pub fn rewind_peeking(&mut self) {
+        self.peeked = 0;
+    }
+
+    /// Returns a `Vec<S::Item>` containing all continuous elements that the
+    /// predicate returns `true` for.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingStream;
+    /// # futures::executor::block_on(async {
+    /// let mut it = PeekingStream::new(futures::stream::iter(0..=3));
+    ///
+    /// assert_eq!(it.next_while(|x| *x < 2).await, vec![0, 1]);
+    /// assert_eq!(it.next().await, Some(2));
+    /// # });
+    /// ```
+    pub async fn next_while<F: Fn(&S::Item) -> bool>(&mut self, pred: F) -> Vec<S::Item> {
+        let mut result = Vec::new();
+
+        self.rewind_peeking();
+
+        while self.fill(1).await && pred(&self.buf[0]) {
+            result.extend(self.buf.pop_front());
+        }
+
+        result
+    }
+
+    /// Consumes `self` and returns the inner stream.
+    ///
+    /// **NOTE:** Any buffered (peeked) items are lost.
+    pub fn into_inner(value: Self) -> S {
+        value.stream
+    }
+}
+
+// Buffered items are never pinned.
+impl<S: Stream + Unpin> Unpin for PeekingStream<S> {}
+
+impl<S: Stream + Unpin> Stream for PeekingStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let this = self.get_mut();
+
+        this.peeked = 0;
+
+        match this.buf.pop_front() {
+            Some(x) => Poll::Ready(Some(x)),
+            None => Pin::new(&mut this.stream).poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.stream.size_hint();
+        let len = self.buf.len();
+
+        (
+            lo.saturating_add(len),
+            hi.and_then(|hi| hi.checked_add(len)),
+        )
+    }
+}
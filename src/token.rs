@@ -0,0 +1,336 @@
+use crate::{peek_core::PeekCore, Outcome, Position};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// What a [`TokenParser`] expected to find.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpectedToken<T> {
+    /// A specific token.
+    Token(T),
+    /// A token matching a predicate.
+    Matching,
+    /// A token described by the given name.
+    Description(&'static str),
+}
+
+/// An error returned by [`TokenParser`]'s matching helpers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenParseError<T> {
+    /// Position after the last consumed token.
+    pub position: Position,
+    /// The offending token, or `None` at the end of input.
+    pub found: Option<T>,
+    /// What was expected instead.
+    pub expected: ExpectedToken<T>,
+}
+
+/// A saved state of a [`TokenParser`].
+///
+/// Created by [`TokenParser::checkpoint()`] and restored by
+/// [`TokenParser::rollback()`].
+#[derive(Clone, Debug)]
+pub struct TokenCheckpoint<I> {
+    iter: I,
+    pos: Position,
+}
+
+impl<I> TokenCheckpoint<I> {
+    /// Returns the position the checkpoint was created at.
+    pub fn position(&self) -> Position {
+        self.pos
+    }
+}
+
+/// A token-level counterpart of [`Parser`](crate::Parser), for a second
+/// pass over the output of a lexer.
+///
+/// Since tokens can be anything, the position is maintained by `update`,
+/// which is called with every consumed or peeked token (so possibly more
+/// than once with the same one). It can capture state, such as a source map
+/// to look token positions up in.
+///
+/// ```rust
+/// # use peeking_iter::parser::{ExpectedToken, TokenParser};
+/// let tokens = ["let", "x", "=", "1", ";"];
+/// let mut it = TokenParser::new(tokens.into_iter(), |pos, tok: &&str| pos.offset += tok.len());
+///
+/// assert_eq!(it.expect("let"), Ok("let"));
+/// assert_eq!(it.next(), Some("x"));
+///
+/// let err = it.expect(":").unwrap_err();
+///
+/// assert_eq!(err.found, Some("="));
+/// assert_eq!(err.expected, ExpectedToken::Token(":"));
+/// assert_eq!(err.position.offset, 4);
+/// ```
+pub struct TokenParser<I, T, U>
+where
+    I: Iterator<Item = T>,
+{
+    core: PeekCore<I, U, Position>,
+}
+
+impl<I, T, U> TokenParser<I, T, U>
+where
+    I: Iterator<Item = T> + Clone,
+    T: Clone,
+    U: FnMut(&mut Position, &T),
+{
+    /// Wraps the given iterator, updating the position with `update`.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::TokenParser;
+    /// // Lexed from "let x\n= 1", with the offsets of the tokens
+    /// let tokens = [("let", 0), ("x", 4), ("=", 6), ("1", 8)];
+    /// let line_starts = [0, 6];
+    ///
+    /// let mut it = TokenParser::new(tokens.into_iter(), |pos, &(tok, start): &(&str, usize)| {
+    ///     pos.line = line_starts.partition_point(|&s| s <= start);
+    ///     pos.col = start - line_starts[pos.line - 1] + tok.len();
+    ///     pos.offset = start + tok.len();
+    /// });
+    ///
+    /// it.next();
+    /// it.next();
+    /// it.next();
+    ///
+    /// assert_eq!((it.position().line, it.position().col), (2, 1));
+    /// ```
+    pub fn new(iter: I, update: U) -> Self {
+        Self {
+            core: PeekCore::new(iter, update, Position::default()),
+        }
+    }
+
+    /// Returns the next token in the inner iterator.
+    ///
+    /// Resets the peeking iterator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<T> {
+        self.core.next()
+    }
+
+    /// Peeks the next token in the inner iterator.
+    ///
+    /// Subsequent calls return subsequent tokens.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::TokenParser;
+    /// let mut it = TokenParser::new([1, 2, 3].into_iter(), |_, _| ());
+    ///
+    /// assert_eq!(it.next(), Some(1));
+    /// assert_eq!(it.peek(), Some(2));
+    /// assert_eq!(it.peek(), Some(3));
+    /// assert_eq!(it.next(), Some(2));
+    /// ```
+    pub fn peek(&mut self) -> Option<T> {
+        self.core.peek()
+    }
+
+    /// Peek the `n`th token in the iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::TokenParser;
+    /// let mut it = TokenParser::new([1, 2, 3].into_iter(), |_, _| ());
+    ///
+    /// assert_eq!(it.peek_nth(1), Some(2));
+    /// assert_eq!(it.peek_nth(0), Some(3));
+    /// assert_eq!(it.next(), Some(1));
+    /// ```
+    pub fn peek_nth(&mut self, n: usize) -> Option<T> {
+        self.core.peek_nth(n)
+    }
+
+    /// Advances the base iterator to the be aligned with the peeking one.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::TokenParser;
+    /// let mut it = TokenParser::new([1, 2, 3].into_iter(), |pos, _| pos.offset += 1);
+    ///
+    /// it.peek();
+    /// it.peek();
+    /// it.advance_to_peeked();
+    ///
+    /// assert_eq!(it.position().offset, 2);
+    /// assert_eq!(it.next(), Some(3));
+    /// ```
+    pub fn advance_to_peeked(&mut self) {
+        self.core.advance_to_peeked();
+    }
+
+    /// Rewind the peeking iterator to align with the base one.
+    pub fn rewind_peeking(&mut self) {
+        self.core.rewind_peeking();
+    }
+
+    /// Returns a `Vec<T>` containing all continuous tokens that the
+    /// predicate returns `true` for.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::TokenParser;
+    /// let mut it = TokenParser::new([1, 2, -1, 3].into_iter(), |_, _| ());
+    ///
+    /// assert_eq!(it.next_while(|x| *x > 0), [1, 2]);
+    /// assert_eq!(it.next(), Some(-1));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn next_while<F: Fn(&T) -> bool>(&mut self, pred: F) -> Vec<T> {
+        let mut result = Vec::new();
+
+        // If `peeking` had already diverged, bring it back
+        self.rewind_peeking();
+
+        while let Some(token) = self.peek().filter(&pred) {
+            result.push(token);
+            self.next();
+        }
+
+        self.rewind_peeking();
+
+        result
+    }
+
+    /// Consumes the next token if it is equal to `token`.
+    ///
+    /// Otherwise returns an error and leaves the parser untouched.
+    pub fn expect(&mut self, token: T) -> Result<T, TokenParseError<T>>
+    where
+        T: PartialEq,
+    {
+        self.rewind_peeking();
+
+        match self.peek() {
+            Some(x) if x == token => {
+                self.next();
+                Ok(x)
+            }
+            found => {
+                self.rewind_peeking();
+                Err(self.error(found, ExpectedToken::Token(token)))
+            }
+        }
+    }
+
+    /// Consumes the next token if it matches `pred`.
+    ///
+    /// Otherwise returns an error expecting `description` and leaves the
+    /// parser untouched.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::{ExpectedToken, TokenParser};
+    /// let mut it = TokenParser::new(["x", "1"].into_iter(), |_, _| ());
+    /// let is_number = |tok: &&str| tok.parse::<i32>().is_ok();
+    ///
+    /// let err = it.expect_matching(is_number, "number").unwrap_err();
+    ///
+    /// assert_eq!(err.expected, ExpectedToken::Description("number"));
+    /// assert_eq!(it.next(), Some("x"));
+    /// assert_eq!(it.expect_matching(is_number, "number"), Ok("1"));
+    /// ```
+    pub fn expect_matching<F: FnOnce(&T) -> bool>(
+        &mut self,
+        pred: F,
+        description: &'static str,
+    ) -> Result<T, TokenParseError<T>> {
+        self.rewind_peeking();
+
+        match self.peek() {
+            Some(x) if pred(&x) => {
+                self.next();
+                Ok(x)
+            }
+            found => {
+                self.rewind_peeking();
+                Err(self.error(found, ExpectedToken::Description(description)))
+            }
+        }
+    }
+
+    /// Saves the current state, to be restored with
+    /// [`rollback()`](Self::rollback()).
+    pub fn checkpoint(&self) -> TokenCheckpoint<I> {
+        let (iter, pos) = self.core.save();
+
+        TokenCheckpoint { iter, pos }
+    }
+
+    /// Restores a state saved by [`checkpoint()`](Self::checkpoint()).
+    ///
+    /// Resets the peeking iterator.
+    pub fn rollback(&mut self, checkpoint: TokenCheckpoint<I>) {
+        self.core.restore((checkpoint.iter, checkpoint.pos));
+    }
+
+    /// Runs `f` against `self`, keeping everything it consumed only if it
+    /// succeeds (returns `Some`/`Ok`).
+    ///
+    /// Otherwise the parser, including its position, is restored to where
+    /// it was before the call.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::TokenParser;
+    /// let mut it = TokenParser::new(["f", "(", ")"].into_iter(), |_, _| ());
+    ///
+    /// assert!(it.attempt(|p| p.expect("f").and_then(|_| p.expect("["))).is_err());
+    /// assert_eq!(it.next(), Some("f"));
+    /// ```
+    pub fn attempt<R: Outcome, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let checkpoint = self.checkpoint();
+        let result = f(self);
+
+        if !result.is_success() {
+            self.rollback(checkpoint);
+        }
+
+        result
+    }
+
+    /// Returns the current position, as maintained by the `update` function.
+    pub fn position(&self) -> Position {
+        self.core.state
+    }
+
+    /// Returns the position at the peeking iterator.
+    ///
+    /// ```rust
+    /// # use peeking_iter::parser::TokenParser;
+    /// let mut it = TokenParser::new(["ab", "c"].into_iter(), |pos, tok: &&str| {
+    ///     pos.offset += tok.len()
+    /// });
+    ///
+    /// it.peek();
+    ///
+    /// assert_eq!(it.peek_position().offset, 2);
+    /// assert_eq!(it.position().offset, 0);
+    /// ```
+    pub fn peek_position(&self) -> Position {
+        *self.core.peek_state()
+    }
+
+    /// Consumes `self` and returns the inner (base) iterator.
+    pub fn into_inner(value: Self) -> I {
+        value.core.iter
+    }
+
+    /// Creates an error at the current position.
+    fn error(&self, found: Option<T>, expected: ExpectedToken<T>) -> TokenParseError<T> {
+        TokenParseError {
+            position: self.core.state,
+            found,
+            expected,
+        }
+    }
+}
+
+impl<I, T, U> Iterator for TokenParser<I, T, U>
+where
+    I: Iterator<Item = T> + Clone,
+    T: Clone,
+    U: FnMut(&mut Position, &T),
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        TokenParser::next(self)
+    }
+}
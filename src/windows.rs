@@ -0,0 +1,71 @@
+use crate::PeekingIter;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Iterator over overlapping windows of `n` items.
+///
+/// Created by [`PeekingIter::windows()`].
+#[cfg(feature = "alloc")]
+pub struct Windows<I: Iterator> {
+    iter: PeekingIter<I>,
+    size: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator + Clone> Windows<I> {
+    pub(crate) fn new(iter: PeekingIter<I>, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+
+        Self { iter, size }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator + Clone> Iterator for Windows<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let mut window = Vec::with_capacity(self.size);
+
+        window.push(self.iter.next()?);
+
+        for _ in 1..self.size {
+            window.push(self.iter.peek()?);
+        }
+
+        Some(window)
+    }
+}
+
+/// Iterator over overlapping windows of `N` items, as arrays.
+///
+/// Created by [`PeekingIter::array_windows()`].
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    iter: PeekingIter<I>,
+}
+
+impl<I: Iterator + Clone, const N: usize> ArrayWindows<I, N> {
+    pub(crate) fn new(iter: PeekingIter<I>) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+
+        Self { iter }
+    }
+}
+
+impl<I: Iterator + Clone, const N: usize> Iterator for ArrayWindows<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<[I::Item; N]> {
+        let mut first = Some(self.iter.next()?);
+        let window: [Option<I::Item>; N] = core::array::from_fn(|i| match i {
+            0 => first.take(),
+            _ => self.iter.peek(),
+        });
+
+        if window.iter().any(Option::is_none) {
+            return None;
+        }
+
+        Some(window.map(Option::unwrap))
+    }
+}
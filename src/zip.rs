@@ -0,0 +1,133 @@
+use crate::PeekingIter;
+
+/// Lock-step adapter over two [`PeekingIter`]s.
+///
+/// Created by [`PeekingIter::zip_peek()`]. Every operation is applied to
+/// both iterators, so their peek cursors always stay in sync.
+pub struct ZipPeek<A: Iterator, B: Iterator> {
+    a: PeekingIter<A>,
+    b: PeekingIter<B>,
+    peeked: usize,
+}
+
+impl<A: Iterator + Clone, B: Iterator + Clone> ZipPeek<A, B> {
+    pub(crate) fn new(a: PeekingIter<A>, b: PeekingIter<B>) -> Self {
+        Self { a, b, peeked: 0 }
+    }
+
+    /// Returns the next pair of items.
+    ///
+    /// Resets both peeking iterators. If either iterator is exhausted,
+    /// neither is advanced.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=2).zip_peek(PeekingIter::new("ab".chars()));
+    ///
+    /// assert_eq!(it.next(), Some((0, 'a')));
+    /// assert_eq!(it.next(), Some((1, 'b')));
+    /// assert_eq!(it.next(), None);
+    ///
+    /// let (mut a, _) = it.unzip();
+    ///
+    /// assert_eq!(a.next(), Some(2));
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(A::Item, B::Item)> {
+        self.rewind_peeking();
+
+        let next = self.peek();
+
+        if next.is_some() {
+            self.advance_to_peeked();
+        }
+
+        next
+    }
+
+    /// Peeks the next pair of items.
+    ///
+    /// Subsequent calls return subsequent pairs.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=2).zip_peek(PeekingIter::new("abc".chars()));
+    ///
+    /// assert_eq!(it.next(), Some((0, 'a')));
+    /// assert_eq!(it.peek(), Some((1, 'b')));
+    /// assert_eq!(it.peek(), Some((2, 'c')));
+    /// assert_eq!(it.peek(), None);
+    /// ```
+    ///
+    /// The peek cursors stay in sync when one iterator is shorter:
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=2).zip_peek(PeekingIter::new("a".chars()));
+    ///
+    /// assert_eq!(it.peek(), Some((0, 'a')));
+    /// assert_eq!(it.peek(), None);
+    ///
+    /// it.advance_to_peeked();
+    ///
+    /// let (mut a, mut b) = it.unzip();
+    ///
+    /// assert_eq!(a.next(), Some(1));
+    /// assert_eq!(b.next(), None);
+    /// ```
+    pub fn peek(&mut self) -> Option<(A::Item, B::Item)> {
+        let a = self.a.peek()?;
+
+        let Some(b) = self.b.peek() else {
+            // Step `a` back, as it already moved past the pair
+            self.a.rewind_peeking();
+
+            for _ in 0..self.peeked {
+                self.a.peek();
+            }
+
+            return None;
+        };
+
+        self.peeked += 1;
+
+        Some((a, b))
+    }
+
+    /// Advances both base iterators to be aligned with their peeking ones.
+    ///
+    /// ```rust
+    /// # use peeking_iter::PeekingIter;
+    /// let mut it = PeekingIter::new(0..=2).zip_peek(PeekingIter::new("abc".chars()));
+    ///
+    /// it.peek();
+    /// it.advance_to_peeked();
+    ///
+    /// assert_eq!(it.next(), Some((1, 'b')));
+    /// ```
+    pub fn advance_to_peeked(&mut self) {
+        self.a.advance_to_peeked();
+        self.b.advance_to_peeked();
+        self.peeked = 0;
+    }
+
+    /// Rewind both peeking iterators to align with the base ones.
+    pub fn rewind_peeking(&mut self) {
+        self.a.rewind_peeking();
+        self.b.rewind_peeking();
+        self.peeked = 0;
+    }
+
+    /// Splits the adapter back into the two [`PeekingIter`]s.
+    pub fn unzip(self) -> (PeekingIter<A>, PeekingIter<B>) {
+        (self.a, self.b)
+    }
+}
+
+impl<A: Iterator + Clone, B: Iterator + Clone> Iterator for ZipPeek<A, B> {
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        ZipPeek::next(self)
+    }
+}